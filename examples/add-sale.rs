@@ -33,13 +33,13 @@ async fn main() -> anyhow::Result<()> {
     let client = vereinsflieger::Client::new(credentials);
 
     let sale = vereinsflieger::NewSale {
-        booking_date: "2025-01-14",
+        booking_date: "2025-01-14".into(),
         article_id: "Lebensmittel",
-        amount: 3.0,
+        amount: 3.0.into(),
         member_id: Some(11011),
         callsign: Some("ClubFridge neo (5x Weizen, 1x Spaten und 2x Chips)"),
         sales_tax: None,
-        total_price: Some(42.15),
+        total_price: Some(42.15.into()),
         counter: None,
         comment: None,
         cost_type: Some("Theke"),
@@ -47,7 +47,8 @@ async fn main() -> anyhow::Result<()> {
         spid: None,
     };
 
-    client.add_sale(&sale).await?;
+    let sale = client.add_sale(&sale).await?;
+    println!("{sale:#?}");
 
     Ok(())
 }
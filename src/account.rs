@@ -0,0 +1,439 @@
+use crate::error::error_for_status;
+use crate::utils::list::parse_list;
+use crate::{BaseUrl, Date, Number, WithAccessToken};
+
+pub async fn add_booking(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    new_booking: &NewBooking<'_>,
+) -> crate::Result<Booking> {
+    let params = WithAccessToken::new(access_token, new_booking);
+
+    let response = client
+        .post(base_url.endpoint("account/add"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    let booking =
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(booking)
+}
+
+pub async fn edit_booking(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    booking_id: u32,
+    update: &BookingUpdate<'_>,
+) -> crate::Result<Booking> {
+    let params = WithAccessToken::new(access_token, update);
+
+    let response = client
+        .put(base_url.endpoint(&format!("account/edit/{booking_id}")))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    let booking =
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(booking)
+}
+
+pub async fn get_booking(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    booking_id: u32,
+) -> crate::Result<Booking> {
+    let params = WithAccessToken::new(access_token, &());
+
+    let response = client
+        .post(base_url.endpoint(&format!("account/get/{booking_id}")))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    let booking =
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(booking)
+}
+
+/// Get all bookings with today's booking date.
+pub async fn list_bookings_today(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> crate::Result<Vec<Booking>> {
+    list_bookings(client, base_url, access_token, "today", &()).await
+}
+
+/// Get all bookings of the given year.
+pub async fn list_bookings_by_year(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    year: u32,
+) -> crate::Result<Vec<Booking>> {
+    #[derive(serde::Serialize)]
+    struct Params {
+        year: u32,
+    }
+
+    let params = Params { year };
+    list_bookings(client, base_url, access_token, "year", &params).await
+}
+
+/// Get all bookings with a booking date within the given date range
+/// (`YYYY-mm-dd`).
+pub async fn list_bookings_by_date_range(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    date_from: &str,
+    date_to: &str,
+) -> crate::Result<Vec<Booking>> {
+    #[derive(serde::Serialize)]
+    struct Params<'a> {
+        #[serde(rename = "datefrom")]
+        date_from: &'a str,
+        #[serde(rename = "dateto")]
+        date_to: &'a str,
+    }
+
+    let params = Params { date_from, date_to };
+    list_bookings(client, base_url, access_token, "daterange", &params).await
+}
+
+async fn list_bookings<T: serde::Serialize>(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    endpoint: &str,
+    params: &T,
+) -> crate::Result<Vec<Booking>> {
+    let params = WithAccessToken::new(access_token, params);
+
+    let response = client
+        .post(base_url.endpoint(&format!("account/list/{endpoint}")))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    parse_list(&bytes)
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct Booking {
+    /// Buchungsnummer
+    #[serde(rename = "adid")]
+    pub booking_id: String,
+    /// Erstellungsdatum
+    #[serde(rename = "createtime")]
+    pub create_time: String,
+    /// Letztes Änderungsdatum
+    #[serde(rename = "modifytime")]
+    pub modify_time: String,
+
+    /// Buchungsdatum
+    #[serde(rename = "bookingdate")]
+    pub booking_date: String,
+    /// Buchungstext
+    #[serde(
+        rename = "bookingtext",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub booking_text: String,
+
+    /// Bruttowert
+    pub value: String,
+    /// Nettowert
+    #[serde(rename = "netvalue")]
+    pub net_value: String,
+    /// Mehrwertsteuer in %
+    #[serde(rename = "salestax")]
+    pub sales_tax: String,
+
+    /// Sollkonto
+    #[serde(rename = "debitaccount")]
+    pub debit_account: String,
+    /// Habenkonto
+    #[serde(rename = "creditaccount")]
+    pub credit_account: String,
+    /// Steuerkonto
+    #[serde(rename = "taxaccount")]
+    pub tax_account: String,
+
+    /// Belegart
+    #[serde(
+        rename = "accountreference",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub account_reference: String,
+    /// Belegnr
+    #[serde(rename = "accountreferenceid")]
+    pub account_reference_id: String,
+
+    /// Gebührenbereich
+    #[serde(
+        rename = "costtype",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub cost_type: String,
+    /// Sphäre
+    #[serde(rename = "spid")]
+    pub sphere: String,
+
+    /// Buchung ist fehlerhaft
+    #[serde(
+        rename = "bookingerror",
+        default,
+        deserialize_with = "crate::utils::serde::flag"
+    )]
+    pub booking_error: bool,
+}
+
+#[cfg(feature = "chrono")]
+impl Booking {
+    /// Returns the day the booking is accounted for.
+    pub fn parsed_booking_date(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.booking_date)
+    }
+
+    /// Returns when the booking was created.
+    pub fn parsed_create_time(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.create_time)
+    }
+
+    /// Returns when the booking was last changed, or `None` if it was never
+    /// changed.
+    pub fn parsed_modify_time(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.modify_time)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Booking {
+    /// Returns the gross amount of the booking.
+    pub fn parsed_value(&self) -> crate::Result<Option<rust_decimal::Decimal>> {
+        crate::utils::decimal::parse_decimal(&self.value)
+    }
+
+    /// Returns the net amount of the booking, i.e. without sales tax.
+    pub fn parsed_net_value(&self) -> crate::Result<Option<rust_decimal::Decimal>> {
+        crate::utils::decimal::parse_decimal(&self.net_value)
+    }
+
+    /// Returns the sales tax rate in percent.
+    pub fn parsed_sales_tax(&self) -> crate::Result<Option<rust_decimal::Decimal>> {
+        crate::utils::decimal::parse_decimal(&self.sales_tax)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct NewBooking<'a> {
+    /// Buchungsdatum (`YYYY-mm-dd`)
+    #[serde(rename = "bookingdate")]
+    pub booking_date: Date<'a>,
+
+    /// Bruttobetrag (muss > 0,00 sein)
+    pub value: Number,
+
+    /// Mehrwertsteuer in % (gültiger MwSt-Eintrag aus den Stammdaten)
+    #[serde(rename = "salestax")]
+    pub sales_tax: Option<Number>,
+
+    /// Sachkontennummer Sollkonto
+    #[serde(rename = "debitaccount")]
+    pub debit_account: &'a str,
+
+    /// Sachkontennummer Habenkonto
+    #[serde(rename = "creditaccount")]
+    pub credit_account: &'a str,
+
+    /// Sachkontennummer Steuerkonto
+    #[serde(rename = "taxaccount")]
+    pub tax_account: Option<&'a str>,
+
+    /// Belegart (Kurzbezeichnung aus den Stammdaten)
+    #[serde(rename = "accountreference")]
+    pub account_reference: Option<&'a str>,
+
+    /// Belegnr
+    #[serde(rename = "accountreferenceid")]
+    pub account_reference_id: Option<u32>,
+
+    /// Buchungstext
+    #[serde(rename = "bookingtext")]
+    pub booking_text: &'a str,
+
+    /// Gebührenbereich
+    #[serde(rename = "costtype")]
+    pub cost_type: Option<&'a str>,
+
+    /// Sphäre
+    pub spid: Option<u32>,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct BookingUpdate<'a> {
+    /// Buchungsdatum (`YYYY-mm-dd`)
+    #[serde(rename = "bookingdate")]
+    pub booking_date: Option<Date<'a>>,
+
+    /// Bruttobetrag (muss > 0,00 sein)
+    pub value: Option<Number>,
+
+    /// Mehrwertsteuer in % (gültiger MwSt-Eintrag aus den Stammdaten)
+    #[serde(rename = "salestax")]
+    pub sales_tax: Option<Number>,
+
+    /// Sachkontennummer Sollkonto
+    #[serde(rename = "debitaccount")]
+    pub debit_account: Option<&'a str>,
+
+    /// Sachkontennummer Habenkonto
+    #[serde(rename = "creditaccount")]
+    pub credit_account: Option<&'a str>,
+
+    /// Sachkontennummer Steuerkonto
+    #[serde(rename = "taxaccount")]
+    pub tax_account: Option<&'a str>,
+
+    /// Belegart (Kurzbezeichnung aus den Stammdaten)
+    #[serde(rename = "accountreference")]
+    pub account_reference: Option<&'a str>,
+
+    /// Belegnr
+    #[serde(rename = "accountreferenceid")]
+    pub account_reference_id: Option<u32>,
+
+    /// Buchungstext
+    #[serde(rename = "bookingtext")]
+    pub booking_text: Option<&'a str>,
+
+    /// Gebührenbereich
+    #[serde(rename = "costtype")]
+    pub cost_type: Option<&'a str>,
+
+    /// Sphäre
+    pub spid: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOOKING: &str = r#"{
+        "adid": "4711",
+        "createtime": "2024-03-05 14:30:00",
+        "modifytime": "0000-00-00 00:00:00",
+        "bookingdate": "2024-03-05",
+        "bookingtext": "Flugzeugmiete D-KABC &amp; Landegeb&uuml;hr",
+        "value": "42.15",
+        "netvalue": "35.42",
+        "salestax": "19",
+        "debitaccount": "10042",
+        "creditaccount": "8400",
+        "taxaccount": "1776",
+        "accountreference": "FL",
+        "accountreferenceid": "123",
+        "costtype": "Segelflug",
+        "spid": "1""#;
+
+    fn parse_booking(booking_error: &str) -> Booking {
+        let json = format!("{BOOKING}{booking_error}}}");
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn booking_list_entry() {
+        let booking = parse_booking(r#", "bookingerror": "1""#);
+        assert_eq!(booking.booking_id, "4711");
+        assert_eq!(booking.booking_text, "Flugzeugmiete D-KABC & Landegebühr");
+        assert_eq!(booking.value, "42.15");
+        assert!(booking.booking_error);
+    }
+
+    #[test]
+    fn booking_error_flag() {
+        assert!(parse_booking(r#", "bookingerror": 1"#).booking_error);
+        assert!(!parse_booking(r#", "bookingerror": "0""#).booking_error);
+        assert!(!parse_booking(r#", "bookingerror": 0"#).booking_error);
+        assert!(!parse_booking("").booking_error);
+    }
+
+    #[test]
+    fn new_booking() {
+        let booking = NewBooking {
+            booking_date: "2024-03-05".into(),
+            value: 42.15.into(),
+            sales_tax: Some(19.0.into()),
+            debit_account: "10042",
+            credit_account: "8400",
+            tax_account: None,
+            account_reference: None,
+            account_reference_id: Some(123),
+            booking_text: "Miete & Gebühr",
+            cost_type: None,
+            spid: None,
+        };
+
+        let params = WithAccessToken::new("token", &booking);
+        assert_eq!(
+            serde_urlencoded::to_string(params).unwrap(),
+            "accesstoken=token&bookingdate=2024-03-05&value=42.15&salestax=19.0\
+             &debitaccount=10042&creditaccount=8400&accountreferenceid=123\
+             &bookingtext=Miete+%26+Geb%C3%BChr"
+        );
+    }
+
+    #[test]
+    fn booking_update_skips_missing_fields() {
+        let update = BookingUpdate {
+            value: Some(10.5.into()),
+            booking_text: Some("Korrektur"),
+            ..Default::default()
+        };
+
+        let params = WithAccessToken::new("token", &update);
+        assert_eq!(
+            serde_urlencoded::to_string(params).unwrap(),
+            "accesstoken=token&value=10.5&bookingtext=Korrektur"
+        );
+    }
+
+    #[cfg(all(feature = "chrono", feature = "rust_decimal"))]
+    #[test]
+    fn typed_values() {
+        use std::str::FromStr;
+
+        let update = BookingUpdate {
+            booking_date: Some(chrono::NaiveDate::from_ymd_opt(2024, 3, 5).unwrap().into()),
+            value: Some(rust_decimal::Decimal::from_str("42.10").unwrap().into()),
+            ..Default::default()
+        };
+
+        let params = WithAccessToken::new("token", &update);
+        assert_eq!(
+            serde_urlencoded::to_string(params).unwrap(),
+            "accesstoken=token&bookingdate=2024-03-05&value=42.10"
+        );
+    }
+}
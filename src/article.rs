@@ -1,14 +1,15 @@
 use crate::error::error_for_status;
-use crate::WithAccessToken;
+use crate::{BaseUrl, WithAccessToken};
 
 pub async fn list_articles(
     client: &reqwest::Client,
+    base_url: &BaseUrl,
     access_token: &str,
 ) -> crate::Result<Vec<Article>> {
     let params = WithAccessToken::new(access_token, &());
 
     let response = client
-        .post("https://www.vereinsflieger.de/interface/rest/articles/list")
+        .post(base_url.endpoint("articles/list"))
         .header("Content-Type", "application/x-www-form-urlencoded")
         .body(serde_urlencoded::to_string(params)?)
         .send()
@@ -16,14 +17,7 @@ pub async fn list_articles(
 
     let bytes = error_for_status(response).await?.bytes().await?;
 
-    let json: serde_json::Map<String, serde_json::Value> =
-        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
-
-    json.into_iter()
-        .filter(|(k, _)| k.parse::<usize>().is_ok())
-        .map(|(_, v)| serde_path_to_error::deserialize(v).map_err(crate::Error::from))
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(Into::into)
+    crate::utils::list::parse_list(&bytes)
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -87,3 +81,31 @@ pub struct Price {
     #[serde(rename = "unitprice")]
     pub unit_price: String,
 }
+
+#[cfg(feature = "chrono")]
+impl Price {
+    /// Returns the first day the price applies, or `None` if it has always
+    /// applied.
+    pub fn parsed_valid_from(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.valid_from)
+    }
+
+    /// Returns the last day the price applies, or `None` if it applies
+    /// indefinitely.
+    pub fn parsed_valid_to(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.valid_to)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Price {
+    /// Returns the sales tax rate in percent.
+    pub fn parsed_sales_tax(&self) -> crate::Result<Option<rust_decimal::Decimal>> {
+        crate::utils::decimal::parse_decimal(&self.sales_tax)
+    }
+
+    /// Returns the gross price per unit.
+    pub fn parsed_unit_price(&self) -> crate::Result<Option<rust_decimal::Decimal>> {
+        crate::utils::decimal::parse_decimal(&self.unit_price)
+    }
+}
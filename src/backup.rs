@@ -0,0 +1,162 @@
+use crate::error::error_for_status;
+use crate::{BaseUrl, WithAccessToken};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Download the backup zip file of the club and write it to `writer`.
+///
+/// The response body is streamed to `writer` in chunks, so the (potentially
+/// large) file is never held in memory completely. Returns the number of
+/// bytes that were written.
+pub async fn download_backup<W>(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    writer: &mut W,
+) -> crate::Result<u64>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let response = request_backup(client, base_url, access_token).await?;
+    write_response(response, writer).await
+}
+
+/// Send the backup request and check the response status, without reading
+/// the response body yet.
+pub(crate) async fn request_backup(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> crate::Result<reqwest::Response> {
+    let params = WithAccessToken::new(access_token, &());
+
+    let response = client
+        .get(base_url.endpoint("backup/getzip"))
+        .query(&params)
+        .send()
+        .await?;
+
+    error_for_status(response).await
+}
+
+/// Stream the body of `response` to `writer`.
+pub(crate) async fn write_response<W>(
+    mut response: reqwest::Response,
+    writer: &mut W,
+) -> crate::Result<u64>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let mut written = 0;
+    while let Some(chunk) = response.chunk().await? {
+        writer.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+
+    writer.flush().await?;
+
+    Ok(written)
+}
+
+/// Read the entries of a downloaded backup zip file.
+///
+/// All entries are decompressed and their checksums are verified, so that
+/// an `Ok` result means that the archive is complete and valid.
+#[cfg(feature = "zip")]
+pub fn read_backup_entries<R>(reader: R) -> crate::Result<Vec<BackupEntry>>
+where
+    R: std::io::Read + std::io::Seek,
+{
+    let mut archive = zip::ZipArchive::new(reader)?;
+
+    let mut entries = Vec::with_capacity(archive.len());
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index)?;
+
+        // The checksum of an entry is only verified once it has been read
+        // completely. Read errors are caused by corrupt data here, so they are
+        // reported as an invalid backup.
+        std::io::copy(&mut file, &mut std::io::sink()).map_err(zip::result::ZipError::Io)?;
+
+        entries.push(BackupEntry {
+            name: file.name().to_string(),
+            size: file.size(),
+            compressed_size: file.compressed_size(),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// An entry of a backup zip file.
+#[cfg(feature = "zip")]
+#[derive(Debug, Clone)]
+pub struct BackupEntry {
+    /// Name of the file within the archive
+    pub name: String,
+    /// Uncompressed size in bytes
+    pub size: u64,
+    /// Compressed size in bytes
+    pub compressed_size: u64,
+}
+
+#[cfg(all(test, feature = "zip"))]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use std::io::{Cursor, Write};
+
+    fn backup() -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+
+        writer.start_file("users.csv", options).unwrap();
+        writer
+            .write_all(&b"uid;firstname;lastname\n".repeat(100))
+            .unwrap();
+        writer.start_file("flights.csv", options).unwrap();
+        writer.write_all(&b"flid;callsign\n".repeat(100)).unwrap();
+
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn valid_backup() {
+        let entries = read_backup_entries(Cursor::new(backup())).unwrap();
+
+        let names = entries.iter().map(|e| e.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["users.csv", "flights.csv"]);
+        assert_eq!(entries[0].size, 2300);
+        assert!(entries[0].compressed_size < entries[0].size);
+    }
+
+    #[test]
+    fn truncated_backup() {
+        let mut data = backup();
+        data.truncate(data.len() / 2);
+
+        let error = read_backup_entries(Cursor::new(data)).unwrap_err();
+        assert!(matches!(error, Error::InvalidBackup(_)), "{error:?}");
+    }
+
+    #[test]
+    fn corrupt_backup() {
+        let mut data = backup();
+
+        // Corrupt the compressed data of the first entry, which starts after
+        // the 30 byte local file header and the file name.
+        let start = 30 + "users.csv".len();
+        for byte in &mut data[start..start + 8] {
+            *byte = !*byte;
+        }
+
+        let error = read_backup_entries(Cursor::new(data)).unwrap_err();
+        assert!(matches!(error, Error::InvalidBackup(_)), "{error:?}");
+    }
+
+    #[test]
+    fn not_a_zip_file() {
+        let error = read_backup_entries(Cursor::new(b"<html></html>".to_vec())).unwrap_err();
+        assert!(matches!(error, Error::InvalidBackup(_)), "{error:?}");
+    }
+}
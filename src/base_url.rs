@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+/// Base URL of the Vereinsflieger API.
+///
+/// Vereinsflieger customers use [`BaseUrl::VEREINSFLIEGER`], while
+/// Flightcenter customers have to use [`BaseUrl::FLIGHTCENTER`]. Any other
+/// URL (e.g. of a local mock server) can be used via [`BaseUrl::new()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseUrl(Cow<'static, str>);
+
+impl BaseUrl {
+    /// Base URL for Vereinsflieger customers.
+    pub const VEREINSFLIEGER: Self = Self(Cow::Borrowed("https://www.vereinsflieger.de"));
+
+    /// Base URL for Flightcenter customers.
+    pub const FLIGHTCENTER: Self = Self(Cow::Borrowed("https://www.flightcenterplus.de"));
+
+    /// Create a custom base URL, e.g. `http://localhost:8080`.
+    ///
+    /// The `/interface/rest/` path is appended automatically and must not be
+    /// part of the given URL.
+    pub fn new(url: impl Into<Cow<'static, str>>) -> Self {
+        let url = url.into();
+        match url.strip_suffix('/') {
+            Some(stripped) => Self(Cow::Owned(stripped.to_string())),
+            None => Self(url),
+        }
+    }
+
+    /// Returns the base URL as a string, without a trailing slash.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the full URL of the given API endpoint, e.g. `user/list`.
+    pub fn endpoint(&self, path: &str) -> String {
+        format!("{}/interface/rest/{path}", self.0)
+    }
+}
+
+impl Default for BaseUrl {
+    fn default() -> Self {
+        Self::VEREINSFLIEGER
+    }
+}
+
+impl Display for BaseUrl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,421 @@
+use crate::error::error_for_status;
+use crate::utils::list::parse_list;
+use crate::{BaseUrl, DateTime, WithAccessToken};
+
+/// Get all appointments within the given date range (`YYYY-mm-dd`).
+pub async fn list_appointments(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    date_from: &str,
+    date_to: &str,
+) -> crate::Result<Vec<Appointment>> {
+    #[derive(serde::Serialize)]
+    struct Params<'a> {
+        #[serde(rename = "datefrom")]
+        date_from: &'a str,
+        #[serde(rename = "dateto")]
+        date_to: &'a str,
+    }
+
+    let params = Params { date_from, date_to };
+    let params = WithAccessToken::new(access_token, &params);
+
+    let response = client
+        .get(base_url.endpoint("calendar/list"))
+        .query(&params)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    parse_list(&bytes)
+}
+
+pub async fn add_appointment(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    new_appointment: &NewAppointment<'_>,
+) -> crate::Result<Appointment> {
+    let params = WithAccessToken::new(access_token, new_appointment);
+
+    let response = client
+        .post(base_url.endpoint("calendar/add"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    let appointment =
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(appointment)
+}
+
+pub async fn edit_appointment(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    appointment_id: u32,
+    update: &AppointmentUpdate<'_>,
+) -> crate::Result<Appointment> {
+    let params = WithAccessToken::new(access_token, update);
+
+    let response = client
+        .put(base_url.endpoint(&format!("calendar/edit/{appointment_id}")))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    let appointment =
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(appointment)
+}
+
+pub async fn delete_appointment(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    appointment_id: u32,
+) -> crate::Result<()> {
+    let params = WithAccessToken::new(access_token, &());
+
+    let response = client
+        .delete(base_url.endpoint(&format!("calendar/delete/{appointment_id}")))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    error_for_status(response).await?;
+
+    Ok(())
+}
+
+/// Get the appointments of the signed-in user, based on the settings of
+/// their ICS calendar.
+pub async fn list_my_appointments(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> crate::Result<Vec<MyAppointment>> {
+    let params = WithAccessToken::new(access_token, &());
+
+    let response = client
+        .get(base_url.endpoint("calendar/list/mycalendar"))
+        .query(&params)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    parse_list(&bytes)
+}
+
+/// Get the public calendar of a club.
+///
+/// This endpoint does not require an access token. Instead, the homepage
+/// access code from "Administration / Homepageerweiterungen / Kalender" is
+/// used to identify the club.
+pub async fn list_public_appointments(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    homepage_access_code: &str,
+) -> crate::Result<Vec<PublicAppointment>> {
+    #[derive(serde::Serialize)]
+    struct Params<'a> {
+        #[serde(rename = "hpaccesscode")]
+        homepage_access_code: &'a str,
+    }
+
+    let params = Params {
+        homepage_access_code,
+    };
+
+    let response = client
+        .post(base_url.endpoint("calendar/list/public"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    parse_list(&bytes)
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct Appointment {
+    /// Eindeutige ID
+    #[serde(rename = "apoid")]
+    pub appointment_id: String,
+    /// Titel
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub title: String,
+    /// Beginn
+    #[serde(rename = "datefrom")]
+    pub date_from: String,
+    /// Ende
+    #[serde(rename = "dateto")]
+    pub date_to: String,
+    /// Anzeige auf externer Homepage
+    #[serde(rename = "exthomepage", deserialize_with = "crate::utils::serde::flag")]
+    pub ext_homepage: bool,
+    /// Ort
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub location: String,
+    /// Beschreibung
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub comment: String,
+    /// URL
+    #[serde(
+        rename = "appointmenturl",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub appointment_url: String,
+}
+
+#[cfg(feature = "chrono")]
+impl Appointment {
+    /// Returns the start of the appointment.
+    pub fn parsed_date_from(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.date_from)
+    }
+
+    /// Returns the end of the appointment.
+    pub fn parsed_date_to(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.date_to)
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct MyAppointment {
+    /// Beginn
+    #[serde(rename = "datefrom")]
+    pub date_from: String,
+    /// Ende
+    #[serde(rename = "dateto")]
+    pub date_to: String,
+    /// Titel
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub title: String,
+    /// Ort
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub location: String,
+    /// Beschreibung
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub description: String,
+    /// Zeitraum im Klartext
+    #[serde(rename = "daterange")]
+    pub date_range: String,
+    /// Dauer im Klartext
+    pub duration: String,
+}
+
+#[cfg(feature = "chrono")]
+impl MyAppointment {
+    /// Returns when the appointment in the personal calendar starts.
+    pub fn parsed_date_from(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.date_from)
+    }
+
+    /// Returns when the appointment in the personal calendar ends.
+    pub fn parsed_date_to(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.date_to)
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PublicAppointment {
+    /// Titel
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub title: String,
+    /// ab
+    #[serde(rename = "datefrom")]
+    pub date_from: String,
+    /// bis
+    #[serde(rename = "dateto")]
+    pub date_to: String,
+    /// Ganztägig
+    #[serde(rename = "wholeday", deserialize_with = "crate::utils::serde::flag")]
+    pub whole_day: bool,
+    /// Ort
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub location: String,
+    /// Kommentar
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub comment: String,
+    /// Zeitraum im Klartext
+    #[serde(rename = "daterange")]
+    pub date_range: String,
+    /// Dauer im Klartext
+    pub duration: String,
+}
+
+#[cfg(feature = "chrono")]
+impl PublicAppointment {
+    /// Returns the start of the public appointment. The time is not
+    /// meaningful for [whole-day](PublicAppointment::whole_day) appointments.
+    pub fn parsed_date_from(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.date_from)
+    }
+
+    /// Returns the end of the public appointment.
+    pub fn parsed_date_to(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.date_to)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct NewAppointment<'a> {
+    /// Titel
+    pub title: &'a str,
+
+    /// Beginn (`YYYY-mm-dd HH:ii`)
+    #[serde(rename = "datefrom")]
+    pub date_from: DateTime<'a>,
+
+    /// Ende (`YYYY-mm-dd HH:ii`)
+    #[serde(rename = "dateto")]
+    pub date_to: DateTime<'a>,
+
+    /// Anzeige auf externer Homepage
+    #[serde(
+        rename = "exthomepage",
+        serialize_with = "crate::utils::serde::serialize_optional_flag"
+    )]
+    pub ext_homepage: Option<bool>,
+
+    /// Ort
+    pub location: Option<&'a str>,
+
+    /// Beschreibung
+    pub comment: Option<&'a str>,
+
+    /// URL
+    #[serde(rename = "appointmenturl")]
+    pub appointment_url: Option<&'a str>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct AppointmentUpdate<'a> {
+    /// Beginn (`YYYY-mm-dd HH:ii`)
+    #[serde(rename = "datefrom")]
+    pub date_from: DateTime<'a>,
+
+    /// Ende (`YYYY-mm-dd HH:ii`)
+    #[serde(rename = "dateto")]
+    pub date_to: DateTime<'a>,
+
+    /// Titel
+    pub title: Option<&'a str>,
+
+    /// Anzeige auf externer Homepage
+    #[serde(
+        rename = "exthomepage",
+        serialize_with = "crate::utils::serde::serialize_optional_flag"
+    )]
+    pub ext_homepage: Option<bool>,
+
+    /// Ort
+    pub location: Option<&'a str>,
+
+    /// Beschreibung
+    pub comment: Option<&'a str>,
+
+    /// URL
+    #[serde(rename = "appointmenturl")]
+    pub appointment_url: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appointment_list_entry() {
+        let json = r#"{
+            "apoid": "123",
+            "title": "Arbeitsdienst &amp; Grillen",
+            "datefrom": "2024-04-13 09:00:00",
+            "dateto": "2024-04-13 18:00:00",
+            "exthomepage": "1",
+            "location": "Werkstatt",
+            "comment": "",
+            "appointmenturl": "https://example.com/?a=1&amp;b=2"
+        }"#;
+
+        let appointment: Appointment = serde_json::from_str(json).unwrap();
+        assert_eq!(appointment.appointment_id, "123");
+        assert_eq!(appointment.title, "Arbeitsdienst & Grillen");
+        assert!(appointment.ext_homepage);
+        assert_eq!(appointment.appointment_url, "https://example.com/?a=1&b=2");
+
+        #[cfg(feature = "chrono")]
+        {
+            let date_from = appointment.parsed_date_from().unwrap().unwrap();
+            assert_eq!(date_from.to_string(), "2024-04-13 09:00:00");
+            let date_to = appointment.parsed_date_to().unwrap().unwrap();
+            assert_eq!(date_to.to_string(), "2024-04-13 18:00:00");
+        }
+    }
+
+    #[test]
+    fn my_appointment_list_entry() {
+        let json = r#"{
+            "datefrom": "2024-04-13 09:00",
+            "dateto": "2024-04-13 18:00",
+            "title": "Arbeitsdienst",
+            "location": "Werkstatt",
+            "description": "Bitte Arbeitskleidung &quot;mitbringen&quot;",
+            "daterange": "13.04.2024 09:00 - 18:00",
+            "duration": "9 Stunden"
+        }"#;
+
+        let appointment: MyAppointment = serde_json::from_str(json).unwrap();
+        assert_eq!(appointment.title, "Arbeitsdienst");
+        assert_eq!(
+            appointment.description,
+            "Bitte Arbeitskleidung \"mitbringen\""
+        );
+        assert_eq!(appointment.date_range, "13.04.2024 09:00 - 18:00");
+
+        #[cfg(feature = "chrono")]
+        {
+            let date_to = appointment.parsed_date_to().unwrap().unwrap();
+            assert_eq!(date_to.to_string(), "2024-04-13 18:00:00");
+        }
+    }
+
+    #[test]
+    fn public_appointment_list_entry() {
+        let json = r#"{
+            "title": "Tag der offenen T&uuml;r",
+            "datefrom": "2024-06-01 00:00:00",
+            "dateto": "2024-06-02 00:00:00",
+            "wholeday": "1",
+            "location": "Flugplatz",
+            "comment": "",
+            "daterange": "01.06.2024 - 02.06.2024",
+            "duration": "2 Tage"
+        }"#;
+
+        let appointment: PublicAppointment = serde_json::from_str(json).unwrap();
+        assert_eq!(appointment.title, "Tag der offenen Tür");
+        assert!(appointment.whole_day);
+        assert_eq!(appointment.duration, "2 Tage");
+
+        #[cfg(feature = "chrono")]
+        {
+            let date_from = appointment.parsed_date_from().unwrap().unwrap();
+            assert_eq!(date_from.to_string(), "2024-06-01 00:00:00");
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use super::{MemoryTokenStore, RateLimit, RateLimiter, RetryPolicy, TokenStore};
+use crate::{BaseUrl, Client, Credentials, Result};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// Builder for a [`Client`] with custom settings.
+///
+/// ```
+/// # fn main() -> vereinsflieger::Result<()> {
+/// # let credentials = vereinsflieger::Credentials {
+/// #     club_id: None,
+/// #     app_key: "app-key".to_string(),
+/// #     username: "username".to_string(),
+/// #     password: "password".to_string(),
+/// #     auth_secret: None,
+/// # };
+/// use std::time::Duration;
+/// use vereinsflieger::{BaseUrl, Client};
+///
+/// let client = Client::builder(credentials)
+///     .base_url(BaseUrl::FLIGHTCENTER)
+///     .timeout(Duration::from_secs(30))
+///     .user_agent("my-app/1.0")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ClientBuilder {
+    credentials: Credentials,
+    base_url: BaseUrl,
+    access_token: Option<String>,
+    rate_limit: Option<RateLimit>,
+    retry_policy: RetryPolicy,
+    token_store: Arc<dyn TokenStore>,
+    http_client: Option<reqwest::Client>,
+    http_client_builder: reqwest::ClientBuilder,
+}
+
+impl ClientBuilder {
+    pub(crate) fn new(credentials: Credentials) -> Self {
+        Self {
+            credentials,
+            base_url: Default::default(),
+            access_token: None,
+            rate_limit: Some(RateLimit::default()),
+            retry_policy: Default::default(),
+            token_store: Arc::new(MemoryTokenStore::new()),
+            http_client: None,
+            http_client_builder: reqwest::Client::builder(),
+        }
+    }
+
+    /// Use a different base URL for all requests, e.g.
+    /// [`BaseUrl::FLIGHTCENTER`] for Flightcenter customers.
+    pub fn base_url(mut self, base_url: BaseUrl) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Use an existing access token for the first requests, instead of
+    /// requesting a new one.
+    ///
+    /// Before the first request, the access token is also saved in the
+    /// [`TokenStore`] of the client, unless the store already contains an
+    /// access token for the same account. If it has expired, a new one is
+    /// requested automatically.
+    pub fn access_token(mut self, access_token: impl Into<String>) -> Self {
+        self.access_token = Some(access_token.into());
+        self
+    }
+
+    /// Limit the number of requests sent to the API, or disable the limit
+    /// with `None`.
+    ///
+    /// The default is one request per second, which is the documented limit
+    /// of the API. Some appkeys have higher quotas, in which case the limit
+    /// can be raised accordingly.
+    pub fn rate_limit(mut self, rate_limit: Option<RateLimit>) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    /// Set the policy for retrying failed requests.
+    ///
+    /// Use [`RetryPolicy::never()`] to disable retries.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the store for the access token, e.g. a [`FileTokenStore`] to
+    /// reuse the access token across process restarts.
+    ///
+    /// The default is a [`MemoryTokenStore`].
+    ///
+    /// [`FileTokenStore`]: crate::FileTokenStore
+    pub fn token_store(mut self, token_store: impl TokenStore + 'static) -> Self {
+        self.token_store = Arc::new(token_store);
+        self
+    }
+
+    /// Use an existing HTTP client for all requests.
+    ///
+    /// This can be used for settings that are not exposed by this builder,
+    /// like custom root certificates. If this is set, the other HTTP
+    /// settings of this builder (timeouts, user agent, proxy) are ignored and
+    /// have to be configured on the given client instead.
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Set a timeout for only the connect phase of the requests.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.connect_timeout(timeout);
+        self
+    }
+
+    /// Set a timeout for the whole requests, from connecting until the
+    /// response body has been received.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.timeout(timeout);
+        self
+    }
+
+    /// Set the `User-Agent` header to identify the application.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.http_client_builder = self.http_client_builder.user_agent(user_agent.into());
+        self
+    }
+
+    /// Send all requests through the given proxy.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.http_client_builder = self.http_client_builder.proxy(proxy);
+        self
+    }
+
+    /// Build the [`Client`].
+    ///
+    /// This fails if the HTTP client can not be initialized, e.g. because the
+    /// TLS backend could not be loaded.
+    pub fn build(self) -> Result<Client> {
+        let client = match self.http_client {
+            Some(http_client) => http_client,
+            None => self.http_client_builder.build()?,
+        };
+
+        Ok(Client {
+            client,
+            base_url: self.base_url,
+            unsaved_access_token: Arc::new(AtomicBool::new(self.access_token.is_some())),
+            access_token: Arc::new(Mutex::new(self.access_token)),
+            refresh_lock: Default::default(),
+            token_store: self.token_store,
+            rate_limiter: Arc::new(RateLimiter::new(self.rate_limit)),
+            retry_policy: self.retry_policy,
+            credentials: Arc::new(self.credentials),
+        })
+    }
+}
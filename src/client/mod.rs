@@ -0,0 +1,995 @@
+mod builder;
+mod rate_limit;
+mod retry;
+mod token_store;
+
+use crate::{
+    Appointment, Article, BaseUrl, Booking, Credentials, CurrentUser, Flight, MaintenanceStatus,
+    MyAppointment, Reservation, Result, Sale, User, Voucher, WorkHourCategory, WorkHours,
+};
+pub use builder::ClientBuilder;
+pub use rate_limit::RateLimit;
+use rate_limit::RateLimiter;
+pub use retry::RetryPolicy;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+pub use token_store::{FileTokenStore, MemoryTokenStore, TokenKey, TokenStore};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Maximum number of access token refreshes per request.
+///
+/// An access token that was loaded from the [`TokenStore`] or refreshed by
+/// another request can be invalid as well, so a request might have to
+/// refresh it more than once before it authenticates itself.
+const MAX_REFRESHES: u32 = 2;
+
+/// Client for the Vereinsflieger API.
+///
+/// This client handles authentication and automatically refreshes the
+/// access token when it expires. Multiple requests can run in parallel, and
+/// if they notice an expired access token at the same time, only one of them
+/// re-authenticates while the others wait for the new access token.
+///
+/// The Vereinsflieger API is rate-limited to one request per second, which
+/// the client enforces by delaying requests if necessary. The limit is shared
+/// by all clones of the client and can be changed via
+/// [`ClientBuilder::rate_limit()`].
+///
+/// Requests that fail due to transient errors, like exceeded rate limits or
+/// connection resets, are retried according to the [`RetryPolicy`], which
+/// can be changed via [`ClientBuilder::retry_policy()`].
+#[derive(Debug, Clone)]
+pub struct Client {
+    /// The internal HTTP client used to make requests.
+    client: reqwest::Client,
+    /// The base URL of the API.
+    base_url: BaseUrl,
+    /// The current access token, if any.
+    access_token: Arc<Mutex<Option<String>>>,
+    /// Whether the current access token was passed to the [`ClientBuilder`]
+    /// and has not been saved in the [`TokenStore`] yet.
+    unsaved_access_token: Arc<AtomicBool>,
+    /// Ensures that only one request refreshes the access token at a time.
+    refresh_lock: Arc<Mutex<()>>,
+    /// The store that persists the access token, e.g. across restarts.
+    token_store: Arc<dyn TokenStore>,
+    /// The rate limiter shared by all clones of this client.
+    rate_limiter: Arc<RateLimiter>,
+    /// The policy for retrying failed requests.
+    retry_policy: RetryPolicy,
+    /// The credentials used to authenticate with the API.
+    credentials: Arc<Credentials>,
+}
+
+/// The result of [`Client::refresh_access_token()`].
+struct RefreshedAccessToken {
+    access_token: String,
+    /// Whether the access token was authenticated by this refresh, instead of
+    /// being reused from another request or client.
+    authenticated: bool,
+}
+
+impl RefreshedAccessToken {
+    fn reused(access_token: String) -> Self {
+        Self {
+            access_token,
+            authenticated: false,
+        }
+    }
+}
+
+impl Client {
+    /// Create a new client with the given credentials.
+    pub fn new(credentials: Credentials) -> Self {
+        Self {
+            client: Default::default(),
+            base_url: Default::default(),
+            access_token: Default::default(),
+            unsaved_access_token: Default::default(),
+            refresh_lock: Default::default(),
+            token_store: Arc::new(MemoryTokenStore::new()),
+            rate_limiter: Arc::new(RateLimiter::new(Some(RateLimit::default()))),
+            retry_policy: Default::default(),
+            credentials: Arc::new(credentials),
+        }
+    }
+
+    /// Create a builder for a client with custom settings, like timeouts or
+    /// a different base URL.
+    pub fn builder(credentials: Credentials) -> ClientBuilder {
+        ClientBuilder::new(credentials)
+    }
+
+    /// Use a different base URL for all requests, e.g.
+    /// [`BaseUrl::FLIGHTCENTER`] for Flightcenter customers.
+    pub fn with_base_url(mut self, base_url: BaseUrl) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Get the new access token from the API and authenticate with it.
+    ///
+    /// This does **not** save the access token for future requests! Use
+    /// [`set_access_token()`](Self::set_access_token) to save the access
+    /// token.
+    ///
+    /// Using this method directly is usually not necessary, but it can be used
+    /// to verify that the credentials are correct.
+    #[tracing::instrument(skip(self))]
+    pub async fn get_access_token(&self) -> Result<String> {
+        debug!("Requesting new access token…");
+        self.rate_limiter.acquire().await;
+        let access_token = crate::get_access_token(&self.client, &self.base_url).await?;
+
+        debug!("Authenticating with new access token…");
+        self.rate_limiter.acquire().await;
+        crate::authenticate(
+            &self.client,
+            &self.base_url,
+            &access_token,
+            &self.credentials,
+        )
+        .await?;
+
+        debug!("Authentication successful");
+        Ok(access_token)
+    }
+
+    /// Get a new access token from the API, authenticate with it and fetch
+    /// the information about the authenticated user.
+    ///
+    /// This works like [`get_access_token()`](Self::get_access_token), but
+    /// additionally reports which user the credentials belong to.
+    #[tracing::instrument(skip(self))]
+    pub async fn get_access_token_and_user(&self) -> Result<(String, CurrentUser)> {
+        let access_token = self.get_access_token().await?;
+
+        debug!("Requesting information about the authenticated user…");
+        self.rate_limiter.acquire().await;
+        let user = crate::get_current_user(&self.client, &self.base_url, &access_token).await?;
+
+        Ok((access_token, user))
+    }
+
+    /// Save the access token for future requests.
+    ///
+    /// The access token is also saved in the [`TokenStore`] of the client.
+    pub async fn set_access_token(&self, access_token: String) {
+        *self.access_token.lock().await = Some(access_token.clone());
+        self.store_access_token(Some(access_token)).await;
+    }
+
+    /// Sign out of the API and clear the saved access token, both in memory
+    /// and in the [`TokenStore`].
+    ///
+    /// This does nothing if no access token is saved.
+    #[tracing::instrument(skip(self))]
+    pub async fn sign_out(&self) -> Result<()> {
+        let access_token = self.access_token.lock().await.take();
+        let access_token = match access_token {
+            Some(access_token) => Some(access_token),
+            None => self.load_access_token().await,
+        };
+        self.store_access_token(None).await;
+
+        if let Some(access_token) = access_token {
+            debug!("Signing out…");
+            self.rate_limiter.acquire().await;
+            crate::sign_out(&self.client, &self.base_url, &access_token).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Run a batch of requests in a separate session and sign out
+    /// afterwards.
+    ///
+    /// The closure receives a client with its own session: it requests a new
+    /// access token for its first request, which is neither shared with this
+    /// client nor saved in its [`TokenStore`]. Signing out of that session
+    /// therefore does not affect this client or its clones. The rate limit is
+    /// still shared with this client.
+    ///
+    /// The session is signed out when the returned future completes,
+    /// regardless of whether it succeeded or failed. A failure to sign out is
+    /// only logged, and the result of the batch is returned. If the future is
+    /// dropped before it completes, or the closure panics, the session is not
+    /// signed out, and its access token stays valid until it expires.
+    pub async fn with_session<T, E, F, R>(&self, f: F) -> std::result::Result<T, E>
+    where
+        F: FnOnce(Client) -> R,
+        R: Future<Output = std::result::Result<T, E>>,
+    {
+        let session = Client {
+            access_token: Default::default(),
+            unsaved_access_token: Default::default(),
+            refresh_lock: Default::default(),
+            token_store: Arc::new(MemoryTokenStore::new()),
+            ..self.clone()
+        };
+
+        let result = f(session.clone()).await;
+
+        if let Err(error) = session.sign_out().await {
+            warn!("Failed to sign out: {error}");
+        }
+
+        result
+    }
+
+    /// Run an idempotent request, retrying it according to the
+    /// [`RetryPolicy`] if it fails.
+    async fn request<T, R, F>(&self, request_fn: F) -> Result<T>
+    where
+        R: Future<Output = Result<T>>,
+        F: Fn(reqwest::Client, BaseUrl, String) -> R,
+    {
+        self.request_with_retries(true, request_fn).await
+    }
+
+    /// Run a request that is not idempotent (e.g. creating a new record),
+    /// which is only retried if the [`RetryPolicy`] explicitly allows it.
+    async fn request_non_idempotent<T, R, F>(&self, request_fn: F) -> Result<T>
+    where
+        R: Future<Output = Result<T>>,
+        F: Fn(reqwest::Client, BaseUrl, String) -> R,
+    {
+        self.request_with_retries(false, request_fn).await
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn request_with_retries<T, R, F>(&self, idempotent: bool, request_fn: F) -> Result<T>
+    where
+        R: Future<Output = Result<T>>,
+        F: Fn(reqwest::Client, BaseUrl, String) -> R,
+    {
+        let mut attempt = 1;
+        loop {
+            let result = self.request_once(&request_fn).await;
+            match result {
+                Err(error) if self.retry_policy.should_retry(&error, attempt, idempotent) => {
+                    let backoff = self.retry_policy.backoff(attempt);
+                    warn!("Request failed ({error}), retrying in {backoff:?}…");
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Run a request with the current access token, refreshing it if necessary.
+    async fn request_once<T, R, F>(&self, request_fn: &F) -> Result<T>
+    where
+        R: Future<Output = Result<T>>,
+        F: Fn(reqwest::Client, BaseUrl, String) -> R,
+    {
+        // Get the current access token, if set. The lock is only held while
+        // reading the token, so that multiple requests can run in parallel.
+        let mut access_token = self.saved_access_token().await;
+        let mut authenticated = false;
+        let mut refreshes = 0;
+
+        loop {
+            // If an access token is known, use it to run the request.
+            if let Some(access_token) = &access_token {
+                debug!("Running request with access token…");
+                self.rate_limiter.acquire().await;
+                let result = request_fn(
+                    self.client.clone(),
+                    self.base_url.clone(),
+                    access_token.clone(),
+                )
+                .await;
+
+                // If the request failed with a "401 Unauthorized" error,
+                // the access token is invalid and needs to be refreshed.
+                //
+                // In all other cases, whether the request succeeded or
+                // failed, return the result as is.
+                if !matches!(result, Err(crate::Error::Unauthorized)) {
+                    return result;
+                }
+
+                // If even a new access token is invalid for some reason, clear
+                // it again, unless it has already been replaced by another
+                // request, and give up.
+                if authenticated || refreshes >= MAX_REFRESHES {
+                    self.clear_access_token(access_token).await;
+                    return result;
+                }
+
+                debug!("Access token is invalid, requesting new access token…");
+            }
+
+            // Get a new access token, unless another request or another
+            // client sharing the token store has already refreshed it.
+            let refreshed = self.refresh_access_token(access_token.as_deref()).await?;
+            access_token = Some(refreshed.access_token);
+            authenticated = refreshed.authenticated;
+            refreshes += 1;
+        }
+    }
+
+    /// Replace the given invalid access token with a new one.
+    ///
+    /// Only one refresh runs at a time. Requests that notice the invalid
+    /// access token while another refresh is running wait for it to finish
+    /// and then use its result, instead of authenticating again. The same
+    /// applies to access tokens that another client has saved in the shared
+    /// [`TokenStore`].
+    async fn refresh_access_token(
+        &self,
+        invalid_access_token: Option<&str>,
+    ) -> Result<RefreshedAccessToken> {
+        let _refresh_guard = self.refresh_lock.lock().await;
+
+        let saved_access_token = self.access_token.lock().await.clone();
+        if let Some(saved_access_token) = saved_access_token {
+            if Some(saved_access_token.as_str()) != invalid_access_token {
+                debug!("Access token has already been refreshed by another request");
+                return Ok(RefreshedAccessToken::reused(saved_access_token));
+            }
+        }
+
+        if let Some(stored_access_token) = self.load_access_token().await {
+            if Some(stored_access_token.as_str()) != invalid_access_token {
+                debug!("Access token has already been refreshed by another client");
+                *self.access_token.lock().await = Some(stored_access_token.clone());
+                return Ok(RefreshedAccessToken::reused(stored_access_token));
+            }
+        }
+
+        // Get a new access token from the API and try to authenticate with it.
+        let new_access_token = self.get_access_token().await?;
+
+        // Save the new access token for future requests.
+        debug!("Saving access token for future requests…");
+        *self.access_token.lock().await = Some(new_access_token.clone());
+        self.store_access_token(Some(new_access_token.clone()))
+            .await;
+
+        Ok(RefreshedAccessToken {
+            access_token: new_access_token,
+            authenticated: true,
+        })
+    }
+
+    /// Clear the given invalid access token, unless it has already been
+    /// replaced by another request.
+    async fn clear_access_token(&self, invalid_access_token: &str) {
+        let mut access_token = self.access_token.lock().await;
+        if access_token.as_deref() == Some(invalid_access_token) {
+            warn!("New access token is invalid, clearing saved access token…");
+            *access_token = None;
+            drop(access_token);
+            self.store_access_token(None).await;
+        }
+    }
+
+    /// Get the saved access token, loading it from the [`TokenStore`] if it
+    /// is not known yet.
+    async fn saved_access_token(&self) -> Option<String> {
+        if let Some(access_token) = self.access_token.lock().await.clone() {
+            if self.unsaved_access_token.swap(false, Ordering::Relaxed) {
+                self.store_initial_access_token(access_token.clone()).await;
+            }
+            return Some(access_token);
+        }
+
+        let loaded_access_token = self.load_access_token().await?;
+
+        // Another request might have set an access token while the store
+        // was being read, which takes precedence.
+        let mut access_token = self.access_token.lock().await;
+        Some(access_token.get_or_insert(loaded_access_token).clone())
+    }
+
+    /// Load the access token from the [`TokenStore`].
+    ///
+    /// The store is accessed on the blocking thread pool of the runtime, so
+    /// that e.g. file system access does not block other requests.
+    async fn load_access_token(&self) -> Option<String> {
+        let token_store = self.token_store.clone();
+        let base_url = self.base_url.clone();
+        let credentials = self.credentials.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            token_store.load(&TokenKey::new(&base_url, &credentials))
+        })
+        .await
+        .unwrap_or_else(|error| Err(std::io::Error::other(error)));
+
+        match result {
+            Ok(access_token) => {
+                if access_token.is_some() {
+                    debug!("Loaded access token from token store");
+                }
+                access_token
+            }
+            Err(error) => {
+                warn!("Failed to load access token from token store: {error}");
+                None
+            }
+        }
+    }
+
+    /// Save the access token that was passed to the [`ClientBuilder`] in the
+    /// [`TokenStore`], unless the store already contains one, which might
+    /// have been refreshed by another client in the meantime.
+    async fn store_initial_access_token(&self, access_token: String) {
+        let token_store = self.token_store.clone();
+        let base_url = self.base_url.clone();
+        let credentials = self.credentials.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let key = TokenKey::new(&base_url, &credentials);
+            match token_store.load(&key)? {
+                Some(_) => Ok(()),
+                None => token_store.save(&key, Some(&access_token)),
+            }
+        })
+        .await
+        .unwrap_or_else(|error| Err(std::io::Error::other(error)));
+
+        if let Err(error) = result {
+            warn!("Failed to save access token in token store: {error}");
+        }
+    }
+
+    /// Save the access token in the [`TokenStore`], or remove it if
+    /// `access_token` is `None`.
+    async fn store_access_token(&self, access_token: Option<String>) {
+        let token_store = self.token_store.clone();
+        let base_url = self.base_url.clone();
+        let credentials = self.credentials.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let key = TokenKey::new(&base_url, &credentials);
+            token_store.save(&key, access_token.as_deref())
+        })
+        .await
+        .unwrap_or_else(|error| Err(std::io::Error::other(error)));
+
+        if let Err(error) = result {
+            warn!("Failed to save access token in token store: {error}");
+        }
+    }
+
+    /// Get the list of all articles from the API.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_articles(&self) -> Result<Vec<Article>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_articles(&client, &base_url, &access_token).await
+        })
+        .await
+    }
+
+    /// Get the information about the authenticated user from the API.
+    #[tracing::instrument(skip_all)]
+    pub async fn current_user(&self) -> Result<CurrentUser> {
+        self.request(|client, base_url, access_token| async move {
+            crate::get_current_user(&client, &base_url, &access_token).await
+        })
+        .await
+    }
+
+    /// Get the list of all users from the API.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_users(&self) -> Result<Vec<User>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_users(&client, &base_url, &access_token).await
+        })
+        .await
+    }
+
+    /// Upload a new sale to the API and return the newly created record.
+    #[tracing::instrument(skip_all)]
+    pub async fn add_sale(&self, sale: &crate::NewSale<'_>) -> Result<Sale> {
+        self.request_non_idempotent(|client, base_url, access_token| async move {
+            crate::add_sale(&client, &base_url, &access_token, sale).await
+        })
+        .await
+    }
+
+    /// Get all sales with a service date within the given date range
+    /// (`YYYY-mm-dd`).
+    #[tracing::instrument(skip(self))]
+    pub async fn list_sales_by_date_range(
+        &self,
+        date_from: &str,
+        date_to: &str,
+    ) -> Result<Vec<Sale>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_sales_by_date_range(&client, &base_url, &access_token, date_from, date_to)
+                .await
+        })
+        .await
+    }
+
+    /// Get all sales that were modified within the last `days` (1–28) days.
+    #[tracing::instrument(skip(self))]
+    pub async fn list_modified_sales(&self, days: u32) -> Result<Vec<Sale>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_modified_sales(&client, &base_url, &access_token, days).await
+        })
+        .await
+    }
+
+    /// Get all sales with the given service date (`YYYY-mm-dd`).
+    #[tracing::instrument(skip(self))]
+    pub async fn list_sales_by_date(&self, date: &str) -> Result<Vec<Sale>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_sales_by_date(&client, &base_url, &access_token, date).await
+        })
+        .await
+    }
+
+    /// Get all sales with today's service date.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_sales_today(&self) -> Result<Vec<Sale>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_sales_today(&client, &base_url, &access_token).await
+        })
+        .await
+    }
+
+    /// Get a single flight from the API.
+    #[tracing::instrument(skip(self))]
+    pub async fn get_flight(&self, flight_id: u32) -> Result<Flight> {
+        self.request(|client, base_url, access_token| async move {
+            crate::get_flight(&client, &base_url, &access_token, flight_id).await
+        })
+        .await
+    }
+
+    /// Upload a new flight to the API.
+    #[tracing::instrument(skip_all)]
+    pub async fn add_flight(&self, flight: &crate::NewFlight<'_>) -> Result<()> {
+        self.request_non_idempotent(|client, base_url, access_token| async move {
+            crate::add_flight(&client, &base_url, &access_token, flight).await
+        })
+        .await
+    }
+
+    /// Update an existing flight.
+    #[tracing::instrument(skip(self, update))]
+    pub async fn edit_flight(
+        &self,
+        flight_id: u32,
+        update: &crate::FlightUpdate<'_>,
+    ) -> Result<()> {
+        self.request(|client, base_url, access_token| async move {
+            crate::edit_flight(&client, &base_url, &access_token, flight_id, update).await
+        })
+        .await
+    }
+
+    /// Delete an existing flight.
+    #[tracing::instrument(skip(self))]
+    pub async fn delete_flight(&self, flight_id: u32) -> Result<()> {
+        self.request(|client, base_url, access_token| async move {
+            crate::delete_flight(&client, &base_url, &access_token, flight_id).await
+        })
+        .await
+    }
+
+    /// Join a glider flight and the corresponding tow plane flight into an
+    /// aerotow.
+    ///
+    /// If the flights can not be joined, [`Error::JoinTowFlightsFailed`] is
+    /// returned instead of triggering a re-authentication. Since the API
+    /// uses "401 Unauthorized" for both cases, the access token is checked
+    /// via `auth/getuser` after a failed join, and the join is only sent
+    /// again if the access token turned out to be invalid.
+    ///
+    /// [`Error::JoinTowFlightsFailed`]: crate::Error::JoinTowFlightsFailed
+    #[tracing::instrument(skip(self))]
+    pub async fn join_tow_flights(&self, flight_id: u32, tow_flight_id: u32) -> Result<()> {
+        let used_access_token = std::sync::Mutex::new(None);
+        let join = |client, base_url, access_token: String| {
+            *used_access_token.lock().unwrap() = Some(access_token.clone());
+            async move {
+                crate::join_tow_flights(&client, &base_url, &access_token, flight_id, tow_flight_id)
+                    .await
+            }
+        };
+
+        let result = self.request(join).await;
+        if !matches!(result, Err(crate::Error::JoinTowFlightsFailed)) {
+            return result;
+        }
+
+        // Check the access token like any other request, which refreshes it
+        // if it is invalid. If the access token that was used for the join
+        // is still the current one afterwards, it was valid and the join
+        // itself has failed.
+        debug!("Joining tow flights failed, checking access token…");
+        self.current_user().await?;
+
+        let used_access_token = used_access_token.lock().unwrap().take();
+        if *self.access_token.lock().await == used_access_token {
+            return result;
+        }
+
+        debug!("Access token was invalid, joining tow flights again…");
+        self.request(join).await
+    }
+
+    /// Get all flights that departed or landed today.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_flights_today(&self) -> Result<Vec<Flight>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_flights_today(&client, &base_url, &access_token).await
+        })
+        .await
+    }
+
+    /// Get all flights that departed or landed on the given date
+    /// (`YYYY-mm-dd`).
+    #[tracing::instrument(skip(self))]
+    pub async fn list_flights_by_date(&self, date: &str) -> Result<Vec<Flight>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_flights_by_date(&client, &base_url, &access_token, date).await
+        })
+        .await
+    }
+
+    /// Get all flights that departed or landed within the given date range
+    /// (`YYYY-mm-dd`).
+    #[tracing::instrument(skip(self))]
+    pub async fn list_flights_by_date_range(
+        &self,
+        date_from: &str,
+        date_to: &str,
+    ) -> Result<Vec<Flight>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_flights_by_date_range(&client, &base_url, &access_token, date_from, date_to)
+                .await
+        })
+        .await
+    }
+
+    /// Get the last `count` (1–100) flights of the given aircraft.
+    #[tracing::instrument(skip(self))]
+    pub async fn list_flights_by_plane(&self, callsign: &str, count: u32) -> Result<Vec<Flight>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_flights_by_plane(&client, &base_url, &access_token, callsign, count).await
+        })
+        .await
+    }
+
+    /// Get the last `count` (1–100) flights of the given user.
+    #[tracing::instrument(skip(self))]
+    pub async fn list_flights_by_user(&self, user_id: u32, count: u32) -> Result<Vec<Flight>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_flights_by_user(&client, &base_url, &access_token, user_id, count).await
+        })
+        .await
+    }
+
+    /// Get the last `count` (1–1000) flights of the authenticated user.
+    #[tracing::instrument(skip(self))]
+    pub async fn list_my_flights(&self, count: u32) -> Result<Vec<Flight>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_my_flights(&client, &base_url, &access_token, count).await
+        })
+        .await
+    }
+
+    /// Get all flights that were modified within the last `days` (1–28) days.
+    #[tracing::instrument(skip(self))]
+    pub async fn list_modified_flights(&self, days: u32) -> Result<Vec<Flight>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_modified_flights(&client, &base_url, &access_token, days).await
+        })
+        .await
+    }
+
+    /// Upload a new booking to the API.
+    ///
+    /// This requires the accounting mode "Version 2" of the club.
+    #[tracing::instrument(skip_all)]
+    pub async fn add_booking(&self, booking: &crate::NewBooking<'_>) -> Result<Booking> {
+        self.request_non_idempotent(|client, base_url, access_token| async move {
+            crate::add_booking(&client, &base_url, &access_token, booking).await
+        })
+        .await
+    }
+
+    /// Update an existing booking.
+    #[tracing::instrument(skip(self, update))]
+    pub async fn edit_booking(
+        &self,
+        booking_id: u32,
+        update: &crate::BookingUpdate<'_>,
+    ) -> Result<Booking> {
+        self.request(|client, base_url, access_token| async move {
+            crate::edit_booking(&client, &base_url, &access_token, booking_id, update).await
+        })
+        .await
+    }
+
+    /// Get a single booking from the API.
+    #[tracing::instrument(skip(self))]
+    pub async fn get_booking(&self, booking_id: u32) -> Result<Booking> {
+        self.request(|client, base_url, access_token| async move {
+            crate::get_booking(&client, &base_url, &access_token, booking_id).await
+        })
+        .await
+    }
+
+    /// Get all bookings with today's booking date.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_bookings_today(&self) -> Result<Vec<Booking>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_bookings_today(&client, &base_url, &access_token).await
+        })
+        .await
+    }
+
+    /// Get all bookings of the given year.
+    #[tracing::instrument(skip(self))]
+    pub async fn list_bookings_by_year(&self, year: u32) -> Result<Vec<Booking>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_bookings_by_year(&client, &base_url, &access_token, year).await
+        })
+        .await
+    }
+
+    /// Get all bookings with a booking date within the given date range
+    /// (`YYYY-mm-dd`).
+    #[tracing::instrument(skip(self))]
+    pub async fn list_bookings_by_date_range(
+        &self,
+        date_from: &str,
+        date_to: &str,
+    ) -> Result<Vec<Booking>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_bookings_by_date_range(
+                &client,
+                &base_url,
+                &access_token,
+                date_from,
+                date_to,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Get all work hours with a date within the given date range
+    /// (`YYYY-mm-dd`).
+    #[tracing::instrument(skip(self))]
+    pub async fn list_work_hours(&self, date_from: &str, date_to: &str) -> Result<Vec<WorkHours>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_work_hours(&client, &base_url, &access_token, date_from, date_to).await
+        })
+        .await
+    }
+
+    /// Upload new work hours to the API and return the newly created record.
+    #[tracing::instrument(skip_all)]
+    pub async fn add_work_hours(&self, work_hours: &crate::NewWorkHours<'_>) -> Result<WorkHours> {
+        self.request_non_idempotent(|client, base_url, access_token| async move {
+            crate::add_work_hours(&client, &base_url, &access_token, work_hours).await
+        })
+        .await
+    }
+
+    /// Get the list of all work hour categories from the API.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_work_hour_categories(&self) -> Result<Vec<WorkHourCategory>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_work_hour_categories(&client, &base_url, &access_token).await
+        })
+        .await
+    }
+
+    /// Get the list of all vouchers from the API.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_vouchers(&self) -> Result<Vec<Voucher>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_vouchers(&client, &base_url, &access_token).await
+        })
+        .await
+    }
+
+    /// Upload a new voucher to the API and return the newly created record.
+    #[tracing::instrument(skip_all)]
+    pub async fn add_voucher(&self, voucher: &crate::NewVoucher<'_>) -> Result<Voucher> {
+        self.request_non_idempotent(|client, base_url, access_token| async move {
+            crate::add_voucher(&client, &base_url, &access_token, voucher).await
+        })
+        .await
+    }
+
+    /// Get all appointments within the given date range (`YYYY-mm-dd`).
+    #[tracing::instrument(skip(self))]
+    pub async fn list_appointments(
+        &self,
+        date_from: &str,
+        date_to: &str,
+    ) -> Result<Vec<Appointment>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_appointments(&client, &base_url, &access_token, date_from, date_to).await
+        })
+        .await
+    }
+
+    /// Get the appointments of the signed-in user, based on the settings of
+    /// their ICS calendar.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_my_appointments(&self) -> Result<Vec<MyAppointment>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_my_appointments(&client, &base_url, &access_token).await
+        })
+        .await
+    }
+
+    /// Upload a new appointment to the API and return the newly created
+    /// record.
+    #[tracing::instrument(skip_all)]
+    pub async fn add_appointment(
+        &self,
+        appointment: &crate::NewAppointment<'_>,
+    ) -> Result<Appointment> {
+        self.request_non_idempotent(|client, base_url, access_token| async move {
+            crate::add_appointment(&client, &base_url, &access_token, appointment).await
+        })
+        .await
+    }
+
+    /// Update an existing appointment and return the updated record.
+    #[tracing::instrument(skip(self, update))]
+    pub async fn edit_appointment(
+        &self,
+        appointment_id: u32,
+        update: &crate::AppointmentUpdate<'_>,
+    ) -> Result<Appointment> {
+        self.request(|client, base_url, access_token| async move {
+            crate::edit_appointment(&client, &base_url, &access_token, appointment_id, update).await
+        })
+        .await
+    }
+
+    /// Delete an existing appointment.
+    #[tracing::instrument(skip(self))]
+    pub async fn delete_appointment(&self, appointment_id: u32) -> Result<()> {
+        self.request(|client, base_url, access_token| async move {
+            crate::delete_appointment(&client, &base_url, &access_token, appointment_id).await
+        })
+        .await
+    }
+
+    /// Get all current reservations from the API.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_active_reservations(&self) -> Result<Vec<Reservation>> {
+        self.request(|client, base_url, access_token| async move {
+            crate::list_active_reservations(&client, &base_url, &access_token).await
+        })
+        .await
+    }
+
+    /// Get the current airframe counters (motor time, flight time and
+    /// number of landings) of the aircraft with the given callsign.
+    ///
+    /// If there is no such aircraft, [`Error::UnknownAircraft`] is returned.
+    /// Since the API only documents "400 Bad Request" as error response, any
+    /// other invalid request is reported the same way.
+    ///
+    /// [`Error::UnknownAircraft`]: crate::Error::UnknownAircraft
+    #[tracing::instrument(skip(self))]
+    pub async fn aircraft_counters(&self, callsign: &str) -> Result<MaintenanceStatus> {
+        self.request(|client, base_url, access_token| async move {
+            crate::get_maintenance_status(&client, &base_url, &access_token, callsign).await
+        })
+        .await
+    }
+
+    /// Download the backup zip file of the club and write it to `writer`.
+    ///
+    /// The response body is streamed to `writer` in chunks instead of being
+    /// buffered in memory. Returns the number of bytes that were written.
+    ///
+    /// Only the initial request is retried according to the [`RetryPolicy`].
+    /// Once data has been written to `writer`, errors are returned as is,
+    /// since the partially written data can not be rolled back.
+    #[tracing::instrument(skip_all)]
+    pub async fn download_backup<W>(&self, writer: &mut W) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin + ?Sized,
+    {
+        let response = self
+            .request(|client, base_url, access_token| async move {
+                crate::backup::request_backup(&client, &base_url, &access_token).await
+            })
+            .await?;
+
+        crate::backup::write_response(response, writer).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+    use tokio::time::Instant;
+
+    fn client(retry_policy: RetryPolicy) -> Client {
+        let credentials = Credentials {
+            club_id: None,
+            app_key: "app-key".to_string(),
+            username: "username".to_string(),
+            password: "password".to_string(),
+            auth_secret: None,
+        };
+
+        Client::builder(credentials)
+            .access_token("token")
+            .rate_limit(None)
+            .retry_policy(retry_policy)
+            .build()
+            .unwrap()
+    }
+
+    /// Run a request that fails with the given error `failures` times, and
+    /// returns the number of attempts and the elapsed time.
+    async fn run(client: &Client, idempotent: bool, failures: u32) -> (u32, Duration) {
+        let attempts = AtomicU32::new(0);
+        let start = Instant::now();
+        let _ = client
+            .request_with_retries(idempotent, |_, _, _| async {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                if attempt <= failures {
+                    Err(Error::TooManyRequests)
+                } else {
+                    Ok(())
+                }
+            })
+            .await;
+
+        (attempts.into_inner(), start.elapsed())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_back_off_exponentially() {
+        let policy = RetryPolicy::default()
+            .max_attempts(4)
+            .initial_backoff(Duration::from_secs(1));
+
+        let (attempts, elapsed) = run(&client(policy), true, 3).await;
+        assert_eq!(attempts, 4);
+
+        // 0.5–1s, 1–2s and 2–4s, because of the jitter
+        assert!(elapsed >= Duration::from_millis(3500), "{elapsed:?}");
+        assert!(elapsed <= Duration::from_secs(7), "{elapsed:?}");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_are_capped_by_max_backoff() {
+        let policy = RetryPolicy::default()
+            .max_attempts(5)
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(2));
+
+        let (attempts, elapsed) = run(&client(policy), true, 10).await;
+        assert_eq!(attempts, 5);
+
+        // 0.5–1s, 1–2s, 1–2s and 1–2s
+        assert!(elapsed >= Duration::from_millis(3500), "{elapsed:?}");
+        assert!(elapsed <= Duration::from_secs(7), "{elapsed:?}");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn non_idempotent_requests_are_not_retried() {
+        let (attempts, elapsed) = run(&client(RetryPolicy::default()), false, 1).await;
+        assert_eq!(attempts, 1);
+        assert_eq!(elapsed, Duration::ZERO);
+
+        let policy = RetryPolicy::default().retry_non_idempotent(true);
+        let (attempts, _) = run(&client(policy), false, 1).await;
+        assert_eq!(attempts, 2);
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::debug;
+
+/// Maximum number of requests that may be sent to the API per time interval.
+///
+/// The limit is enforced by a token bucket: up to `requests` requests can be
+/// sent in a burst, after which the client waits until enough time has
+/// passed for the next request. The default is one request per second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    requests: u32,
+    interval: Duration,
+}
+
+impl RateLimit {
+    /// Allow `requests` requests per `interval`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests` is zero.
+    pub const fn new(requests: u32, interval: Duration) -> Self {
+        assert!(requests > 0, "`requests` must be greater than zero");
+        Self { requests, interval }
+    }
+
+    /// Allow `requests` requests per second.
+    pub const fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    /// The time it takes until one more request may be sent.
+    fn refill_interval(&self) -> Duration {
+        self.interval / self.requests
+    }
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self::per_second(1)
+    }
+}
+
+/// Token bucket that enforces a [`RateLimit`].
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: Option<RateLimit>,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Number of requests that may currently be sent. This is negative if
+    /// requests are already waiting for a free slot.
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: Option<RateLimit>) -> Self {
+        let tokens = limit.map(|limit| limit.requests).unwrap_or_default();
+
+        Self {
+            limit,
+            bucket: Mutex::new(Bucket {
+                tokens: tokens as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Wait until the next request may be sent.
+    pub(crate) async fn acquire(&self) {
+        let Some(limit) = self.limit else {
+            return;
+        };
+
+        let refill_interval = limit.refill_interval().as_secs_f64();
+
+        // Reserve a token while holding the lock, but wait for it outside of
+        // the lock, so that concurrent callers are served in order.
+        let wait = {
+            let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+
+            let now = Instant::now();
+            let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed / refill_interval).min(limit.requests as f64);
+            bucket.last_refill = now;
+
+            bucket.tokens -= 1.0;
+            if bucket.tokens >= 0.0 {
+                return;
+            }
+
+            Duration::from_secs_f64(-bucket.tokens * refill_interval)
+        };
+
+        debug!("Rate limit reached, waiting {wait:?}…");
+        tokio::time::sleep(wait).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Acquire `count` slots and return the elapsed time at each of them.
+    async fn acquire_times(limiter: &RateLimiter, count: usize) -> Vec<Duration> {
+        let start = Instant::now();
+
+        let mut times = Vec::with_capacity(count);
+        for _ in 0..count {
+            limiter.acquire().await;
+            times.push(start.elapsed());
+        }
+        times
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn spaces_requests_at_the_configured_rate() {
+        let limiter = RateLimiter::new(Some(RateLimit::per_second(1)));
+
+        let times = acquire_times(&limiter, 4).await;
+        assert_eq!(times, [0, 1, 2, 3].map(Duration::from_secs));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn allows_bursts_up_to_the_limit() {
+        let limiter = RateLimiter::new(Some(RateLimit::new(2, Duration::from_secs(1))));
+
+        let times = acquire_times(&limiter, 4).await;
+        assert_eq!(times, [0, 0, 500, 1000].map(Duration::from_millis));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn refills_while_idle() {
+        let limiter = RateLimiter::new(Some(RateLimit::per_second(1)));
+        limiter.acquire().await;
+
+        tokio::time::sleep(Duration::from_secs(5)).await;
+
+        // The bucket only holds one token, so only one request may be sent
+        // immediately after the pause.
+        let times = acquire_times(&limiter, 2).await;
+        assert_eq!(times, [0, 1].map(Duration::from_secs));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn serves_concurrent_callers_in_order() {
+        let limiter = std::sync::Arc::new(RateLimiter::new(Some(RateLimit::per_second(1))));
+        let start = Instant::now();
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for _ in 0..3 {
+            let limiter = limiter.clone();
+            tasks.spawn(async move {
+                limiter.acquire().await;
+                start.elapsed()
+            });
+        }
+
+        let mut times = tasks.join_all().await;
+        times.sort();
+        assert_eq!(times, [0, 1, 2].map(Duration::from_secs));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn does_not_wait_without_limit() {
+        let limiter = RateLimiter::new(None);
+
+        let times = acquire_times(&limiter, 3).await;
+        assert_eq!(times, [Duration::ZERO; 3]);
+    }
+}
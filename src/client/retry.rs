@@ -0,0 +1,180 @@
+use crate::Error;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Policy for retrying failed requests.
+///
+/// Failed requests are retried with an exponential backoff: the first retry
+/// waits for roughly [`initial_backoff()`](Self::initial_backoff), and
+/// every further retry waits twice as long as the previous one, up to
+/// [`max_backoff()`](Self::max_backoff). A random jitter is applied to each
+/// backoff, so that multiple clients don't retry in lockstep.
+///
+/// Requests that create new records (e.g. [`Client::add_sale()`]) are not
+/// idempotent and are never retried, unless
+/// [`retry_non_idempotent()`](Self::retry_non_idempotent) is enabled.
+///
+/// [`Client::add_sale()`]: crate::Client::add_sale
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    is_retryable: fn(&Error) -> bool,
+    retry_non_idempotent: bool,
+}
+
+impl RetryPolicy {
+    /// Never retry failed requests.
+    pub fn never() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// Set the maximum number of attempts per request, including the first
+    /// one. A value of `1` disables retries.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the backoff before the first retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Set the maximum backoff between two attempts.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Set which errors are retried.
+    ///
+    /// The default is [`Error::is_transient()`].
+    pub fn retry_if(mut self, is_retryable: fn(&Error) -> bool) -> Self {
+        self.is_retryable = is_retryable;
+        self
+    }
+
+    /// Also retry requests that are not idempotent, like adding a new sale.
+    ///
+    /// This can lead to duplicate records, if a request was processed by the
+    /// API, but the response got lost, e.g. due to a connection reset.
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Returns whether a request that failed on the given attempt (starting
+    /// at `1`) should be retried.
+    pub(crate) fn should_retry(&self, error: &Error, attempt: u32, idempotent: bool) -> bool {
+        attempt < self.max_attempts
+            && (idempotent || self.retry_non_idempotent)
+            && (self.is_retryable)(error)
+    }
+
+    /// Returns the backoff after the given failed attempt (starting at `1`).
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+
+        // Wait for a random duration between half and the full backoff.
+        let jitter = RandomState::new().build_hasher().finish() as f64 / u64::MAX as f64;
+        backoff.mul_f64(0.5 + jitter / 2.)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            is_retryable: Error::is_transient,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_jittered(backoff: Duration, expected: Duration) {
+        assert!(
+            backoff >= expected / 2 && backoff <= expected,
+            "{backoff:?} is not between {:?} and {expected:?}",
+            expected / 2
+        );
+    }
+
+    #[test]
+    fn backoff_grows_exponentially() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(60));
+
+        for (attempt, expected) in [(1, 1), (2, 2), (3, 4), (4, 8), (5, 16)] {
+            assert_jittered(policy.backoff(attempt), Duration::from_secs(expected));
+        }
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(5));
+
+        assert_jittered(policy.backoff(4), Duration::from_secs(5));
+        assert_jittered(policy.backoff(u32::MAX), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        let policy = RetryPolicy::default().initial_backoff(Duration::from_millis(800));
+
+        let backoffs = (0..200).map(|_| policy.backoff(1)).collect::<Vec<_>>();
+        for backoff in &backoffs {
+            assert_jittered(*backoff, Duration::from_millis(800));
+        }
+
+        // The backoffs are actually randomized.
+        assert!(backoffs.iter().any(|backoff| *backoff != backoffs[0]));
+    }
+
+    #[test]
+    fn max_attempts_is_honoured() {
+        let policy = RetryPolicy::default().max_attempts(3);
+        assert!(policy.should_retry(&Error::TooManyRequests, 1, true));
+        assert!(policy.should_retry(&Error::TooManyRequests, 2, true));
+        assert!(!policy.should_retry(&Error::TooManyRequests, 3, true));
+
+        let policy = RetryPolicy::never();
+        assert!(!policy.should_retry(&Error::TooManyRequests, 1, true));
+    }
+
+    #[test]
+    fn only_retryable_errors_are_retried() {
+        let policy = RetryPolicy::default();
+        assert!(!policy.should_retry(&Error::Unauthorized, 1, true));
+        assert!(!policy.should_retry(&Error::JoinTowFlightsFailed, 1, true));
+
+        let policy = policy.retry_if(|error| matches!(error, Error::Unauthorized));
+        assert!(policy.should_retry(&Error::Unauthorized, 1, true));
+        assert!(!policy.should_retry(&Error::TooManyRequests, 1, true));
+    }
+
+    #[test]
+    fn non_idempotent_requests_are_not_retried_by_default() {
+        let policy = RetryPolicy::default();
+        assert!(!policy.should_retry(&Error::TooManyRequests, 1, false));
+
+        let policy = policy.retry_non_idempotent(true);
+        assert!(policy.should_retry(&Error::TooManyRequests, 1, false));
+    }
+}
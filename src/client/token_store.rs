@@ -0,0 +1,283 @@
+use crate::md5::md5_hex;
+use crate::{BaseUrl, Credentials};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hasher};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Identifies the account that an access token belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TokenKey<'a> {
+    /// Basis-URL der API
+    ///
+    /// Access tokens are only valid for the API that issued them, so the
+    /// same account on different APIs needs different keys.
+    pub base_url: &'a str,
+    /// Eindeutiger Applikationsschlüssel
+    pub app_key: &'a str,
+    /// Benutzername oder E-Mail-Adresse
+    pub username: &'a str,
+    /// Eindeutige Nummer des Vereins
+    pub club_id: Option<u32>,
+}
+
+impl<'a> TokenKey<'a> {
+    /// Create the key for the given credentials on the given API.
+    pub fn new(base_url: &'a BaseUrl, credentials: &'a Credentials) -> Self {
+        Self {
+            base_url: base_url.as_str(),
+            app_key: &credentials.app_key,
+            username: &credentials.username,
+            club_id: credentials.club_id,
+        }
+    }
+}
+
+impl TokenKey<'_> {
+    /// Returns a string that uniquely identifies this key and is safe to use
+    /// as a file name.
+    fn digest(&self) -> String {
+        let club_id = self.club_id.map(|id| id.to_string()).unwrap_or_default();
+        md5_hex(&format!(
+            "{}\0{}\0{}\0{}",
+            self.base_url, self.app_key, self.username, club_id
+        ))
+    }
+}
+
+/// Storage for the access tokens of a [`Client`](crate::Client).
+///
+/// The client loads the access token from the store before its first
+/// request, saves it whenever it has been refreshed, and removes it when it
+/// turns out to be invalid or the client signs out. This way, a still valid
+/// access token can be reused, e.g. across process restarts with the
+/// [`FileTokenStore`].
+///
+/// Failing to load or save an access token is not fatal. The client logs a
+/// warning and continues as if no access token was saved.
+///
+/// The methods of this trait may block, e.g. on file system access. The
+/// client calls them via [`tokio::task::spawn_blocking()`], so they never
+/// block the async runtime.
+pub trait TokenStore: Debug + Send + Sync {
+    /// Load the saved access token for the given key, if any.
+    fn load(&self, key: &TokenKey<'_>) -> std::io::Result<Option<String>>;
+
+    /// Save the access token for the given key, or remove the saved access
+    /// token if `access_token` is `None`.
+    fn save(&self, key: &TokenKey<'_>, access_token: Option<&str>) -> std::io::Result<()>;
+}
+
+impl<T: TokenStore + ?Sized> TokenStore for Arc<T> {
+    fn load(&self, key: &TokenKey<'_>) -> std::io::Result<Option<String>> {
+        (**self).load(key)
+    }
+
+    fn save(&self, key: &TokenKey<'_>, access_token: Option<&str>) -> std::io::Result<()> {
+        (**self).save(key, access_token)
+    }
+}
+
+/// [`TokenStore`] that keeps the access tokens in memory.
+///
+/// This is the default store of the [`Client`](crate::Client). Sharing the
+/// same store (via [`Arc`]) between multiple clients with the same
+/// credentials lets them reuse the access tokens of each other: a client
+/// that notices an invalid access token first checks whether another client
+/// has already saved a new one, before authenticating again. Since the
+/// clients do not coordinate their refreshes, two clients may still
+/// authenticate at the same time, though.
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    access_tokens: Mutex<HashMap<String, String>>,
+}
+
+impl MemoryTokenStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self, key: &TokenKey<'_>) -> std::io::Result<Option<String>> {
+        let access_tokens = self.access_tokens.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(access_tokens.get(&key.digest()).cloned())
+    }
+
+    fn save(&self, key: &TokenKey<'_>, access_token: Option<&str>) -> std::io::Result<()> {
+        let mut access_tokens = self.access_tokens.lock().unwrap_or_else(|e| e.into_inner());
+        match access_token {
+            Some(access_token) => access_tokens.insert(key.digest(), access_token.to_string()),
+            None => access_tokens.remove(&key.digest()),
+        };
+        Ok(())
+    }
+}
+
+/// [`TokenStore`] that saves the access tokens as files in a directory.
+///
+/// Each combination of base URL, appkey, username and club ID is saved in a
+/// separate file, so multiple accounts can share the same directory. On Unix, the
+/// files are only readable by the current user.
+///
+/// Access tokens are written to a temporary file first, which then replaces
+/// the previous file, so that readers never see a partially written token.
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    directory: PathBuf,
+}
+
+impl FileTokenStore {
+    /// Create a store that saves the access tokens in the given directory.
+    ///
+    /// The directory is created when the first access token is saved.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(&self, key: &TokenKey<'_>) -> PathBuf {
+        self.directory.join(format!("{}.token", key.digest()))
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self, key: &TokenKey<'_>) -> std::io::Result<Option<String>> {
+        match std::fs::read_to_string(self.path(key)) {
+            Ok(access_token) => {
+                let access_token = access_token.trim();
+                Ok((!access_token.is_empty()).then(|| access_token.to_string()))
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn save(&self, key: &TokenKey<'_>, access_token: Option<&str>) -> std::io::Result<()> {
+        let path = self.path(key);
+
+        let Some(access_token) = access_token else {
+            return match std::fs::remove_file(path) {
+                Err(error) if error.kind() != ErrorKind::NotFound => Err(error),
+                _ => Ok(()),
+            };
+        };
+
+        std::fs::create_dir_all(&self.directory)?;
+
+        // The temporary file is always created from scratch, so that the
+        // permissions apply even if the previous file was readable by others.
+        let temp_path = self.directory.join(format!(
+            ".{}.{}.tmp",
+            key.digest(),
+            RandomState::new().build_hasher().finish()
+        ));
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let result = options.open(&temp_path).and_then(|mut file| {
+            std::io::Write::write_all(&mut file, access_token.as_bytes())?;
+            file.sync_all()?;
+            std::fs::rename(&temp_path, path)
+        });
+
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credentials(club_id: Option<u32>) -> Credentials {
+        Credentials {
+            club_id,
+            app_key: "app-key".to_string(),
+            username: "username".to_string(),
+            password: "password".to_string(),
+            auth_secret: None,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let directory = tempfile::tempdir().unwrap();
+        let store = FileTokenStore::new(directory.path().join("tokens"));
+        let credentials = credentials(None);
+        let key = TokenKey::new(&BaseUrl::VEREINSFLIEGER, &credentials);
+
+        assert_eq!(store.load(&key).unwrap(), None);
+
+        store.save(&key, Some("first")).unwrap();
+        assert_eq!(store.load(&key).unwrap().as_deref(), Some("first"));
+
+        store.save(&key, Some("second")).unwrap();
+        assert_eq!(store.load(&key).unwrap().as_deref(), Some("second"));
+
+        store.save(&key, None).unwrap();
+        assert_eq!(store.load(&key).unwrap(), None);
+
+        // Removing a missing access token is not an error.
+        store.save(&key, None).unwrap();
+
+        // No temporary files are left behind.
+        let files = std::fs::read_dir(directory.path().join("tokens")).unwrap();
+        assert_eq!(files.count(), 0);
+    }
+
+    #[test]
+    fn keys_are_separated_by_base_url_and_club() {
+        let directory = tempfile::tempdir().unwrap();
+        let store = FileTokenStore::new(directory.path());
+
+        let first_club = credentials(Some(1));
+        let second_club = credentials(Some(2));
+        let keys = [
+            TokenKey::new(&BaseUrl::VEREINSFLIEGER, &first_club),
+            TokenKey::new(&BaseUrl::FLIGHTCENTER, &first_club),
+            TokenKey::new(&BaseUrl::VEREINSFLIEGER, &second_club),
+        ];
+
+        for (index, key) in keys.iter().enumerate() {
+            store.save(key, Some(&index.to_string())).unwrap();
+        }
+
+        for (index, key) in keys.iter().enumerate() {
+            assert_eq!(store.load(key).unwrap(), Some(index.to_string()));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn files_are_only_readable_by_the_owner() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let directory = tempfile::tempdir().unwrap();
+        let store = FileTokenStore::new(directory.path());
+        let credentials = credentials(None);
+        let key = TokenKey::new(&BaseUrl::VEREINSFLIEGER, &credentials);
+
+        // An existing file that is readable by everyone is replaced.
+        let path = store.path(&key);
+        std::fs::write(&path, "old").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        store.save(&key, Some("new")).unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(store.load(&key).unwrap().as_deref(), Some("new"));
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Serialize, Serializer};
+
+/// A date that is sent to the API.
+///
+/// Dates can either be passed as strings in the `YYYY-mm-dd` format, or, if
+/// the `chrono` feature is enabled, as `chrono::NaiveDate` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Date<'a> {
+    /// Datum im Format `YYYY-mm-dd`
+    Str(&'a str),
+    /// Datum als [`chrono::NaiveDate`]
+    #[cfg(feature = "chrono")]
+    Naive(chrono::NaiveDate),
+}
+
+impl<'a> From<&'a str> for Date<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Str(value)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Date<'_> {
+    fn from(value: chrono::NaiveDate) -> Self {
+        Self::Naive(value)
+    }
+}
+
+impl Serialize for Date<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Str(value) => serializer.serialize_str(value),
+            #[cfg(feature = "chrono")]
+            Self::Naive(value) => serializer.collect_str(value),
+        }
+    }
+}
+
+/// A date and time that is sent to the API.
+///
+/// Like [`Date`], these can either be passed as strings in the
+/// `YYYY-mm-dd HH:ii` format, or, if the `chrono` feature is enabled, as
+/// `chrono::NaiveDateTime` values, which are sent without seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateTime<'a> {
+    /// Datum und Uhrzeit im Format `YYYY-mm-dd HH:ii`
+    Str(&'a str),
+    /// Datum und Uhrzeit als [`chrono::NaiveDateTime`]
+    #[cfg(feature = "chrono")]
+    Naive(chrono::NaiveDateTime),
+}
+
+impl<'a> From<&'a str> for DateTime<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Str(value)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for DateTime<'_> {
+    fn from(value: chrono::NaiveDateTime) -> Self {
+        Self::Naive(value)
+    }
+}
+
+impl Serialize for DateTime<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Str(value) => serializer.serialize_str(value),
+            #[cfg(feature = "chrono")]
+            Self::Naive(value) => {
+                use chrono::Timelike;
+
+                let (date, time) = (value.date(), value.time());
+                serializer.collect_str(&format_args!(
+                    "{date} {:02}:{:02}",
+                    time.hour(),
+                    time.minute()
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn naive_datetime_is_sent_without_seconds() {
+        let value = chrono::NaiveDate::from_ymd_opt(2024, 3, 5)
+            .unwrap()
+            .and_hms_opt(9, 5, 30)
+            .unwrap();
+
+        let json = serde_json::to_string(&DateTime::from(value)).unwrap();
+        assert_eq!(json, "\"2024-03-05 09:05\"");
+    }
+}
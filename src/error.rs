@@ -3,11 +3,43 @@ use reqwest::{Response, StatusCode};
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
     #[error("The request used an invalid access token or was not authenticated")]
     Unauthorized,
     #[error("The maximum number of requests has been exceeded")]
     TooManyRequests,
+    #[error("The flights could not be joined into an aerotow")]
+    JoinTowFlightsFailed,
+    #[error("There is no aircraft with the callsign `{0}`")]
+    UnknownAircraft(String),
+    #[error("The sign-in failed: {0}")]
+    InvalidCredentials(String),
+    #[error("The sign-in requires a valid two-factor authentication code")]
+    TwoFactorRequired,
+    #[cfg(feature = "totp")]
+    #[error("Failed to generate the two-factor authentication code: {0}")]
+    TotpFailed(String),
+    #[error("`{name}` must be between {min} and {max}, but was {value}")]
+    OutOfRange {
+        name: &'static str,
+        value: u32,
+        min: u32,
+        max: u32,
+    },
+    #[error("`{name}` must be a finite, non-negative number of hours, but was {value}")]
+    InvalidMotorCounter { name: &'static str, value: f64 },
+    #[cfg(feature = "chrono")]
+    #[error("`{0}` is not a valid date")]
+    InvalidDate(String),
+    #[cfg(feature = "rust_decimal")]
+    #[error("`{0}` is not a valid number")]
+    InvalidNumber(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[cfg(feature = "zip")]
+    #[error("The backup is not a valid zip file: {0}")]
+    InvalidBackup(#[from] zip::result::ZipError),
     #[error(transparent)]
     RequestFailed(#[from] reqwest::Error),
     #[error(transparent)]
@@ -16,6 +48,27 @@ pub enum Error {
     DeserializationFailed(#[from] serde_path_to_error::Error<serde_json::Error>),
 }
 
+impl Error {
+    /// Returns whether the error is likely temporary, i.e. whether the same
+    /// request might succeed if it is sent again later.
+    ///
+    /// This is the case for exceeded rate limits, connection errors, timeouts
+    /// and "5xx" server errors.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::TooManyRequests => true,
+            Error::RequestFailed(error) => {
+                error.is_connect()
+                    || error.is_timeout()
+                    || error
+                        .status()
+                        .is_some_and(|status| status.is_server_error())
+            }
+            _ => false,
+        }
+    }
+}
+
 pub async fn error_for_status(response: Response) -> Result<Response> {
     #[derive(Debug, serde::Deserialize)]
     struct ErrorResponse {
@@ -34,13 +87,127 @@ pub async fn error_for_status(response: Response) -> Result<Response> {
         }
     } else if error.status() == Some(StatusCode::FORBIDDEN) {
         if let Ok(json) = response.json::<ErrorResponse>().await {
-            if json.error.starts_with("Die maximale Anzahl der Requests")
-                && json.error.ends_with("ist überschritten!")
-            {
+            if is_too_many_requests(&json.error) {
+                return Err(Error::TooManyRequests);
+            }
+        }
+    }
+
+    Err(error.into())
+}
+
+/// Like [`error_for_status()`], but for the response of the `auth/signin`
+/// endpoint, which uses "403 Forbidden" for all failed sign-ins.
+pub async fn sign_in_error_for_status(response: Response) -> Result<Response> {
+    #[derive(Debug, serde::Deserialize)]
+    struct ErrorResponse {
+        error: String,
+        #[serde(default, deserialize_with = "crate::utils::serde::flag")]
+        need_2fa: bool,
+    }
+
+    let Err(error) = response.error_for_status_ref() else {
+        return Ok(response);
+    };
+
+    if error.status() == Some(StatusCode::FORBIDDEN) {
+        if let Ok(json) = response.json::<ErrorResponse>().await {
+            if json.need_2fa {
+                return Err(Error::TwoFactorRequired);
+            } else if is_too_many_requests(&json.error) {
                 return Err(Error::TooManyRequests);
+            } else {
+                return Err(Error::InvalidCredentials(json.error));
             }
         }
     }
 
     Err(error.into())
 }
+
+fn is_too_many_requests(message: &str) -> bool {
+    message.starts_with("Die maximale Anzahl der Requests")
+        && message.ends_with("ist überschritten!")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn error_for(template: ResponseTemplate) -> Error {
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(template)
+            .mount(&server)
+            .await;
+
+        let response = reqwest::get(server.uri()).await.unwrap();
+        error_for_status(response).await.unwrap_err()
+    }
+
+    #[tokio::test]
+    async fn server_errors_are_transient() {
+        assert!(error_for(ResponseTemplate::new(503)).await.is_transient());
+        assert!(error_for(ResponseTemplate::new(500)).await.is_transient());
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_transient() {
+        assert!(!error_for(ResponseTemplate::new(400)).await.is_transient());
+        assert!(!error_for(ResponseTemplate::new(404)).await.is_transient());
+
+        let unauthorized = ResponseTemplate::new(401)
+            .set_body_json(serde_json::json!({ "error": "Unauthorized" }));
+        let error = error_for(unauthorized).await;
+        assert!(matches!(error, Error::Unauthorized));
+        assert!(!error.is_transient());
+    }
+
+    #[tokio::test]
+    async fn exceeded_rate_limits_are_transient() {
+        let message = "Die maximale Anzahl der Requests (1/Sekunde) ist überschritten!";
+        let forbidden =
+            ResponseTemplate::new(403).set_body_json(serde_json::json!({ "error": message }));
+        let error = error_for(forbidden).await;
+        assert!(matches!(error, Error::TooManyRequests));
+        assert!(error.is_transient());
+    }
+
+    #[tokio::test]
+    async fn connection_errors_are_transient() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+
+        let error = reqwest::get(format!("http://{address}")).await.unwrap_err();
+        assert!(Error::from(error).is_transient());
+    }
+
+    #[tokio::test]
+    async fn timeouts_are_transient() {
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+            .mount(&server)
+            .await;
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+        let error = client.get(server.uri()).send().await.unwrap_err();
+        assert!(Error::from(error).is_transient());
+    }
+
+    #[tokio::test]
+    async fn invalid_requests_are_not_transient() {
+        let error = reqwest::Client::new()
+            .get("not a url")
+            .send()
+            .await
+            .unwrap_err();
+        assert!(!Error::from(error).is_transient());
+    }
+}
@@ -0,0 +1,893 @@
+use crate::error::error_for_status;
+use crate::utils::list::parse_list;
+use crate::utils::range::check_range;
+use crate::{BaseUrl, DateTime, Error, WithAccessToken};
+use reqwest::StatusCode;
+
+pub async fn get_flight(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    flight_id: u32,
+) -> crate::Result<Flight> {
+    let params = WithAccessToken::new(access_token, &());
+
+    let response = client
+        .post(base_url.endpoint(&format!("flight/get/{flight_id}")))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    let flight =
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(flight)
+}
+
+pub async fn add_flight(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    new_flight: &NewFlight<'_>,
+) -> crate::Result<()> {
+    new_flight.details.check()?;
+
+    let params = WithAccessToken::new(access_token, new_flight);
+
+    let response = client
+        .post(base_url.endpoint("flight/add"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    error_for_status(response).await?;
+
+    Ok(())
+}
+
+pub async fn edit_flight(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    flight_id: u32,
+    update: &FlightUpdate<'_>,
+) -> crate::Result<()> {
+    update.details.check()?;
+
+    let params = WithAccessToken::new(access_token, update);
+
+    let response = client
+        .put(base_url.endpoint(&format!("flight/edit/{flight_id}")))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    error_for_status(response).await?;
+
+    Ok(())
+}
+
+pub async fn delete_flight(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    flight_id: u32,
+) -> crate::Result<()> {
+    let params = WithAccessToken::new(access_token, &());
+
+    let response = client
+        .delete(base_url.endpoint(&format!("flight/delete/{flight_id}")))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    error_for_status(response).await?;
+
+    Ok(())
+}
+
+/// Join a glider flight and the corresponding tow plane flight into an
+/// aerotow.
+///
+/// The API responds with "401 Unauthorized" both for failed joins and for
+/// invalid access tokens, so both are returned as
+/// [`Error::JoinTowFlightsFailed`]. [`Client::join_tow_flights()`] tells them
+/// apart by checking the access token afterwards.
+///
+/// [`Client::join_tow_flights()`]: crate::Client::join_tow_flights
+pub async fn join_tow_flights(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    flight_id: u32,
+    tow_flight_id: u32,
+) -> crate::Result<()> {
+    #[derive(serde::Serialize)]
+    struct Params {
+        #[serde(rename = "flid")]
+        flight_id: u32,
+        #[serde(rename = "flidtow")]
+        tow_flight_id: u32,
+    }
+
+    let params = Params {
+        flight_id,
+        tow_flight_id,
+    };
+    let params = WithAccessToken::new(access_token, &params);
+
+    let response = client
+        .put(base_url.endpoint("flight/jointowflights"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    match error_for_status(response).await {
+        Ok(_) => Ok(()),
+        Err(Error::Unauthorized) => Err(Error::JoinTowFlightsFailed),
+        Err(Error::RequestFailed(error)) if error.status() == Some(StatusCode::UNAUTHORIZED) => {
+            Err(Error::JoinTowFlightsFailed)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Get all flights that departed or landed today.
+pub async fn list_flights_today(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> crate::Result<Vec<Flight>> {
+    list_flights(client, base_url, access_token, "today", &()).await
+}
+
+/// Get all flights that departed or landed on the given date (`YYYY-mm-dd`).
+pub async fn list_flights_by_date(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    date: &str,
+) -> crate::Result<Vec<Flight>> {
+    #[derive(serde::Serialize)]
+    struct Params<'a> {
+        #[serde(rename = "dateparam")]
+        date: &'a str,
+    }
+
+    let params = Params { date };
+    list_flights(client, base_url, access_token, "date", &params).await
+}
+
+/// Get all flights that departed or landed within the given date range
+/// (`YYYY-mm-dd`).
+pub async fn list_flights_by_date_range(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    date_from: &str,
+    date_to: &str,
+) -> crate::Result<Vec<Flight>> {
+    #[derive(serde::Serialize)]
+    struct Params<'a> {
+        #[serde(rename = "datefrom")]
+        date_from: &'a str,
+        #[serde(rename = "dateto")]
+        date_to: &'a str,
+    }
+
+    let params = Params { date_from, date_to };
+    list_flights(client, base_url, access_token, "daterange", &params).await
+}
+
+/// Get the last `count` (1–100) flights of the given aircraft.
+pub async fn list_flights_by_plane(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    callsign: &str,
+    count: u32,
+) -> crate::Result<Vec<Flight>> {
+    #[derive(serde::Serialize)]
+    struct Params<'a> {
+        callsign: &'a str,
+        count: u32,
+    }
+
+    check_range("count", count, 1..=100)?;
+
+    let params = Params { callsign, count };
+    list_flights(client, base_url, access_token, "plane", &params).await
+}
+
+/// Get the last `count` (1–100) flights of the given user, both as pilot
+/// and as flight instructor.
+pub async fn list_flights_by_user(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    user_id: u32,
+    count: u32,
+) -> crate::Result<Vec<Flight>> {
+    #[derive(serde::Serialize)]
+    struct Params {
+        #[serde(rename = "uid")]
+        user_id: u32,
+        count: u32,
+    }
+
+    check_range("count", count, 1..=100)?;
+
+    let params = Params { user_id, count };
+    list_flights(client, base_url, access_token, "user", &params).await
+}
+
+/// Get the last `count` (1–1000) flights of the authenticated user, both as
+/// pilot and as flight instructor.
+pub async fn list_my_flights(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    count: u32,
+) -> crate::Result<Vec<Flight>> {
+    #[derive(serde::Serialize)]
+    struct Params {
+        count: u32,
+    }
+
+    check_range("count", count, 1..=1000)?;
+
+    let params = Params { count };
+    list_flights(client, base_url, access_token, "myflights", &params).await
+}
+
+/// Get all flights that were modified within the last `days` (1–28) days.
+pub async fn list_modified_flights(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    days: u32,
+) -> crate::Result<Vec<Flight>> {
+    #[derive(serde::Serialize)]
+    struct Params {
+        days: u32,
+    }
+
+    check_range("days", days, 1..=28)?;
+
+    let params = Params { days };
+    list_flights(client, base_url, access_token, "modified", &params).await
+}
+
+async fn list_flights<T: serde::Serialize>(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    endpoint: &str,
+    params: &T,
+) -> crate::Result<Vec<Flight>> {
+    let params = WithAccessToken::new(access_token, params);
+
+    let response = client
+        .post(base_url.endpoint(&format!("flight/list/{endpoint}")))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    parse_list(&bytes)
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct Flight {
+    /// Eindeutige Flugnummer
+    #[serde(rename = "flid")]
+    pub flight_id: String,
+    /// Datum der Erstellung
+    #[serde(rename = "createtime")]
+    pub create_time: String,
+    /// Datum der letzten Änderung
+    #[serde(rename = "modifytime")]
+    pub modify_time: String,
+
+    /// Eindeutige LFZ-Nummer
+    #[serde(rename = "apid")]
+    pub airplane_id: String,
+    /// LFZ-Kennzeichen
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub callsign: String,
+    /// Flugzeugtyp
+    #[serde(
+        rename = "planetype",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub plane_type: String,
+
+    /// Eindeutige Benutzernummer des Piloten
+    #[serde(rename = "uidpilot")]
+    pub pilot_user_id: String,
+    /// Pilot
+    #[serde(
+        rename = "pilotname",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub pilot_name: String,
+    /// Mitgliedsnummer des Piloten im Verein
+    #[serde(rename = "pilotmemberid")]
+    pub pilot_member_id: String,
+
+    /// Eindeutige Benutzernummer des Begleiters / FI
+    #[serde(rename = "uidattendant")]
+    pub attendant_user_id: String,
+    /// Begleiter / FI
+    #[serde(
+        rename = "attendantname",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub attendant_name: String,
+    /// Mitgliedsnummer des Begleiters im Verein
+    #[serde(rename = "attendantmemberid")]
+    pub attendant_member_id: String,
+
+    /// Eindeutige Benutzernummer des 2. Begleiters
+    #[serde(rename = "uidattendant2")]
+    pub attendant2_user_id: String,
+    /// 2. Begleiter
+    #[serde(
+        rename = "attendantname2",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub attendant2_name: String,
+
+    /// Eindeutige Benutzernummer des 3. Begleiters
+    #[serde(rename = "uidattendant3")]
+    pub attendant3_user_id: String,
+    /// 3. Begleiter
+    #[serde(
+        rename = "attendantname3",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub attendant3_name: String,
+
+    /// Eindeutige Benutzernummer (Flugauftrag von)
+    #[serde(rename = "uidfi")]
+    pub fi_user_id: String,
+    /// Flugauftrag von
+    #[serde(rename = "finame", deserialize_with = "crate::utils::serde::unescape")]
+    pub fi_name: String,
+
+    /// Eindeutige Benutzernummer (Anderes Mitglied zahlt)
+    #[serde(rename = "uidcharge")]
+    pub charge_user_id: String,
+
+    /// Flugdatum
+    #[serde(rename = "dateofflight")]
+    pub date_of_flight: String,
+    /// Abflugzeit
+    #[serde(rename = "departuretime")]
+    pub departure_time: String,
+    /// Startort
+    #[serde(
+        rename = "departurelocation",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub departure_location: String,
+    /// Landezeit
+    #[serde(rename = "arrivaltime")]
+    pub arrival_time: String,
+    /// Landeort
+    #[serde(
+        rename = "arrivallocation",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub arrival_location: String,
+    /// Flugzeit
+    #[serde(rename = "flighttime")]
+    pub flight_time: String,
+    /// Anzahl der Landungen
+    #[serde(rename = "landingcount")]
+    pub landing_count: String,
+
+    /// Startart
+    #[serde(rename = "starttype")]
+    pub start_type: StartType,
+    /// Flugmodus (1=Lokal, 2=Abflug, 3=Landung, 4=Fremd)
+    #[serde(rename = "flightmode")]
+    pub flight_mode: FlightMode,
+    /// Id der Flugart (Siehe Stammdaten Flugarten)
+    #[serde(rename = "ftid")]
+    pub flight_type_id: String,
+
+    /// Kommentar
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub comment: String,
+
+    /// OffBlock-Zeit
+    #[serde(rename = "offblock")]
+    pub off_block: String,
+    /// OnBlock-Zeit
+    #[serde(rename = "onblock")]
+    pub on_block: String,
+    /// Blockzeit
+    #[serde(rename = "blocktime")]
+    pub block_time: String,
+
+    /// Motorzählerstand bei Start (Industriestunden)
+    #[serde(rename = "motorstart")]
+    pub motor_start: String,
+    /// Motorzählerstand bei Landung (Industriestunden)
+    #[serde(rename = "motorend")]
+    pub motor_end: String,
+
+    /// Eindeutige Flugnummer des zugehörigen Schleppfluges
+    #[serde(rename = "flidtow")]
+    pub tow_flight_id: String,
+
+    /// Abrechnungsmodus (1=Keine, 2=Pilot, 3=Begleiter, 4=Gastflug,
+    /// 5=Pilot+Begleiter, 6=Gastflug (Pilot zahlt), 7=Anderes Mitglied,
+    /// 8=Über SF, 9=Landegebühr)
+    #[serde(rename = "chargemode")]
+    pub charge_mode: ChargeMode,
+
+    /// Id der Winde
+    #[serde(rename = "wid")]
+    pub winch_id: String,
+    /// Windenfahrer
+    #[serde(rename = "uidwinch")]
+    pub winch_user_id: String,
+
+    /// Fluggebühren (nach der Abrechnung verfügbar)
+    #[serde(rename = "invoiceinfo", default)]
+    pub invoice_info: Vec<InvoiceInfo>,
+}
+
+#[cfg(feature = "chrono")]
+impl Flight {
+    /// Returns the day of the flight.
+    pub fn parsed_date_of_flight(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.date_of_flight)
+    }
+
+    /// Returns the departure time in UTC, or `None` for flights that have
+    /// not departed yet.
+    pub fn parsed_departure_time(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.departure_time)
+    }
+
+    /// Returns the landing time in UTC, or `None` for flights that have not
+    /// landed yet.
+    pub fn parsed_arrival_time(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.arrival_time)
+    }
+
+    /// Returns when the flight was entered.
+    pub fn parsed_create_time(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.create_time)
+    }
+
+    /// Returns when the flight was last changed, or `None` if it was never
+    /// changed.
+    pub fn parsed_modify_time(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.modify_time)
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct InvoiceInfo {
+    /// Benutzernummer
+    #[serde(rename = "uid")]
+    pub user_id: String,
+    /// ID der Buchungsnummer
+    #[serde(rename = "adid")]
+    pub booking_id: String,
+    /// Name
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub name: String,
+    /// Brutto Abrechnungsbetrag
+    pub value: String,
+}
+
+#[cfg(feature = "rust_decimal")]
+impl InvoiceInfo {
+    /// Returns the gross amount that is charged for the flight.
+    pub fn parsed_value(&self) -> crate::Result<Option<rust_decimal::Decimal>> {
+        crate::utils::decimal::parse_decimal(&self.value)
+    }
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct NewFlight<'a> {
+    /// LFZ-Kennzeichen
+    pub callsign: &'a str,
+
+    /// Weitere Angaben zum Flug
+    #[serde(flatten)]
+    pub details: FlightDetails<'a>,
+
+    /// Flugzeit in Minuten, falls abweichend von Start- und Landezeit
+    #[serde(rename = "flighttime")]
+    pub flight_time: Option<u32>,
+
+    /// Blockzeit in Minuten, falls abweichend von Offblock und Onblock
+    #[serde(rename = "blocktime")]
+    pub block_time: Option<u32>,
+
+    /// uid des Fluglehrers bei Flugauftrag
+    #[serde(rename = "uidfi")]
+    pub fi_user_id: Option<u32>,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct FlightUpdate<'a> {
+    /// LFZ-Kennzeichen
+    pub callsign: Option<&'a str>,
+
+    /// Weitere Angaben zum Flug
+    #[serde(flatten)]
+    pub details: FlightDetails<'a>,
+}
+
+/// Angaben zu einem Flug, die sowohl beim Anlegen als auch beim Bearbeiten
+/// gesetzt werden können
+///
+/// Abweichende Flug- und Blockzeiten sowie der Fluglehrer bei Flugauftrag
+/// können laut API-Dokumentation nur beim Anlegen angegeben werden, siehe
+/// [`NewFlight`].
+#[derive(Debug, Default, serde::Serialize)]
+pub struct FlightDetails<'a> {
+    /// Name des Piloten
+    #[serde(rename = "pilotname")]
+    pub pilot_name: Option<&'a str>,
+
+    /// uid des Piloten
+    #[serde(rename = "uidpilot")]
+    pub pilot_user_id: Option<u32>,
+
+    /// Name des Begleiters / FI
+    #[serde(rename = "attendantname")]
+    pub attendant_name: Option<&'a str>,
+
+    /// uid des Begleiters / FI
+    #[serde(rename = "uidattendant")]
+    pub attendant_user_id: Option<u32>,
+
+    /// Name des 2. Begleiters
+    #[serde(rename = "attendantname2")]
+    pub attendant2_name: Option<&'a str>,
+
+    /// uid des 2. Begleiters
+    #[serde(rename = "uidattendant2")]
+    pub attendant2_user_id: Option<u32>,
+
+    /// Name des 3. Begleiters
+    #[serde(rename = "attendantname3")]
+    pub attendant3_name: Option<&'a str>,
+
+    /// uid des 3. Begleiters
+    #[serde(rename = "uidattendant3")]
+    pub attendant3_user_id: Option<u32>,
+
+    /// Startart
+    #[serde(rename = "starttype")]
+    pub start_type: Option<LaunchMethod>,
+
+    /// Startzeit in UTC (`YYYY-mm-dd HH:ii`)
+    #[serde(rename = "departuretime")]
+    pub departure_time: Option<DateTime<'a>>,
+
+    /// Startort (Name oder ICAO oder Koordinaten `LAT,LON`)
+    #[serde(rename = "departurelocation")]
+    pub departure_location: Option<&'a str>,
+
+    /// Landezeit in UTC (`YYYY-mm-dd HH:ii`)
+    #[serde(rename = "arrivaltime")]
+    pub arrival_time: Option<DateTime<'a>>,
+
+    /// Landeort
+    #[serde(rename = "arrivallocation")]
+    pub arrival_location: Option<&'a str>,
+
+    /// Anzahl der Landungen (Standard: 1)
+    #[serde(rename = "landingcount")]
+    pub landing_count: Option<u32>,
+
+    /// Flugart (Standard: 10, 8 = Schulflug)
+    #[serde(rename = "ftid")]
+    pub flight_type_id: Option<u32>,
+
+    /// Strecke in km
+    pub km: Option<u32>,
+
+    /// Abrechnungsmodus (1=keine, 2=Pilot, 3=Begleiter, 4=Gast,
+    /// 5=Pilot+Begleiter, 7=Anderes Mitglied)
+    #[serde(rename = "chargemode")]
+    pub charge_mode: Option<ChargeMode>,
+
+    /// uid des zahlenden Mitglieds
+    #[serde(rename = "uidcharge")]
+    pub charge_user_id: Option<u32>,
+
+    /// Bemerkung
+    pub comment: Option<&'a str>,
+
+    /// Callsign des Schleppflugzeugs
+    #[serde(rename = "towcallsign")]
+    pub tow_callsign: Option<&'a str>,
+
+    /// Pilot des Schleppflugzeugs
+    #[serde(rename = "towpilotname")]
+    pub tow_pilot_name: Option<&'a str>,
+
+    /// uid des Schlepppiloten
+    #[serde(rename = "towuidpilot")]
+    pub tow_pilot_user_id: Option<u32>,
+
+    /// Schleppzeit in Minuten
+    #[serde(rename = "towtime")]
+    pub tow_time: Option<u32>,
+
+    /// Schlepphöhe in Meter
+    #[serde(rename = "towheight")]
+    pub tow_height: Option<u32>,
+
+    /// Offblock in UTC (`HH:ii`)
+    #[serde(rename = "offblock")]
+    pub off_block: Option<&'a str>,
+
+    /// Onblock in UTC (`HH:ii`)
+    #[serde(rename = "onblock")]
+    pub on_block: Option<&'a str>,
+
+    /// Motorzählerstand bei Start
+    #[serde(rename = "motorstart")]
+    pub motor_start: Option<MotorCounter>,
+
+    /// Motorzählerstand bei Landung
+    #[serde(rename = "motorend")]
+    pub motor_end: Option<MotorCounter>,
+
+    /// Id der Winde
+    #[serde(rename = "wid")]
+    pub winch_id: Option<u32>,
+
+    /// uid des Windenfahrers
+    #[serde(rename = "uidwinch")]
+    pub winch_user_id: Option<u32>,
+}
+
+impl FlightDetails<'_> {
+    fn check(&self) -> crate::Result<()> {
+        if let Some(motor_start) = &self.motor_start {
+            motor_start.check("motor_start")?;
+        }
+        if let Some(motor_end) = &self.motor_end {
+            motor_end.check("motor_end")?;
+        }
+        Ok(())
+    }
+}
+
+crate::utils::serde::code_enum! {
+    /// Startart eines vorhandenen Fluges
+    pub enum StartType {
+        /// Eigenstart (`1`)
+        SelfLaunch = "1",
+        /// F-Schlepp (`3`)
+        Aerotow = "3",
+        /// Winde (`5`)
+        Winch = "5",
+        /// Gummiseil (`7`)
+        Bungee = "7",
+        /// Fahrzeug (`9`)
+        Vehicle = "9",
+    }
+}
+
+crate::utils::serde::code_enum! {
+    /// Startart beim Anlegen oder Bearbeiten eines Fluges
+    pub enum LaunchMethod {
+        /// Eigenstart (`E`)
+        SelfLaunch = "E",
+        /// Winde (`W`)
+        Winch = "W",
+        /// F-Schlepp (`F`)
+        Aerotow = "F",
+    }
+}
+
+crate::utils::serde::code_enum! {
+    /// Flugmodus
+    pub enum FlightMode {
+        /// Lokal (`1`)
+        Local = "1",
+        /// Abflug (`2`)
+        Departure = "2",
+        /// Landung (`3`)
+        Arrival = "3",
+        /// Fremd (`4`)
+        External = "4",
+    }
+}
+
+crate::utils::serde::code_enum! {
+    /// Abrechnungsmodus
+    pub enum ChargeMode {
+        /// Keine (`1`)
+        NoCharge = "1",
+        /// Pilot (`2`)
+        Pilot = "2",
+        /// Begleiter (`3`)
+        Attendant = "3",
+        /// Gastflug (`4`)
+        Guest = "4",
+        /// Pilot+Begleiter (`5`)
+        PilotAndAttendant = "5",
+        /// Gastflug (Pilot zahlt) (`6`)
+        GuestPilotPays = "6",
+        /// Anderes Mitglied (`7`)
+        OtherMember = "7",
+        /// Über SF (`8`)
+        ViaGliderFlight = "8",
+        /// Landegebühr (`9`)
+        LandingFee = "9",
+    }
+}
+
+/// Motorzählerstand, entweder in Stunden und Minuten oder als
+/// Industriestunden.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MotorCounter {
+    /// Stunden und Minuten (`HH:ii`, Minuten < 60)
+    HoursMinutes(u32, u32),
+    /// Industriestunden (max. 5 Nachkommastellen)
+    DecimalHours(f64),
+}
+
+impl MotorCounter {
+    /// Ensure that the minutes of [`MotorCounter::HoursMinutes`] are valid
+    /// and that [`MotorCounter::DecimalHours`] is a finite, non-negative
+    /// number.
+    fn check(&self, name: &'static str) -> crate::Result<()> {
+        match self {
+            Self::HoursMinutes(_, minutes) => check_range(name, *minutes, 0..=59),
+            Self::DecimalHours(hours) if !is_valid_decimal_hours(*hours) => {
+                Err(Error::InvalidMotorCounter {
+                    name,
+                    value: *hours,
+                })
+            }
+            Self::DecimalHours(_) => Ok(()),
+        }
+    }
+}
+
+fn is_valid_decimal_hours(hours: f64) -> bool {
+    hours.is_finite() && hours >= 0.0
+}
+
+impl serde::Serialize for MotorCounter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::HoursMinutes(_, minutes) if *minutes >= 60 => Err(serde::ser::Error::custom(
+                format!("invalid motor counter minutes: {minutes}"),
+            )),
+            Self::HoursMinutes(hours, minutes) => {
+                serializer.serialize_str(&format!("{hours:02}:{minutes:02}"))
+            }
+            Self::DecimalHours(hours) if !is_valid_decimal_hours(*hours) => Err(
+                serde::ser::Error::custom(format!("invalid motor counter hours: {hours}")),
+            ),
+            Self::DecimalHours(hours) => {
+                // `abs()` turns `-0.0` into `0.0`, everything else is positive
+                let formatted = format!("{:.5}", hours.abs());
+                let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+                serializer.serialize_str(formatted)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_flight_serializes_flattened_details() {
+        let flight = NewFlight {
+            callsign: "D-KABC",
+            details: FlightDetails {
+                pilot_user_id: Some(42),
+                start_type: Some(LaunchMethod::Winch),
+                motor_start: Some(MotorCounter::HoursMinutes(1, 5)),
+                ..Default::default()
+            },
+            flight_time: Some(75),
+            block_time: None,
+            fi_user_id: Some(7),
+        };
+
+        let params = WithAccessToken::new("token", &flight);
+        assert_eq!(
+            serde_urlencoded::to_string(params).unwrap(),
+            "accesstoken=token&callsign=D-KABC&uidpilot=42&starttype=W&motorstart=01%3A05\
+             &flighttime=75&uidfi=7"
+        );
+    }
+
+    #[test]
+    fn motor_counter_rejects_invalid_minutes() {
+        let details = FlightDetails {
+            motor_end: Some(MotorCounter::HoursMinutes(1, 75)),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            details.check(),
+            Err(Error::OutOfRange {
+                name: "motor_end",
+                value: 75,
+                ..
+            })
+        ));
+        assert!(serde_urlencoded::to_string(&details).is_err());
+    }
+
+    #[test]
+    fn motor_counter_rejects_invalid_decimal_hours() {
+        for hours in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -0.5] {
+            let details = FlightDetails {
+                motor_start: Some(MotorCounter::DecimalHours(hours)),
+                ..Default::default()
+            };
+
+            assert!(matches!(
+                details.check(),
+                Err(Error::InvalidMotorCounter {
+                    name: "motor_start",
+                    ..
+                })
+            ));
+            assert!(serde_urlencoded::to_string(&details).is_err());
+        }
+    }
+
+    #[test]
+    fn motor_counter_decimal_hours() {
+        let details = FlightDetails {
+            motor_start: Some(MotorCounter::DecimalHours(-0.0)),
+            motor_end: Some(MotorCounter::DecimalHours(1234.56789)),
+            ..Default::default()
+        };
+
+        details.check().unwrap();
+        assert_eq!(
+            serde_urlencoded::to_string(&details).unwrap(),
+            "motorstart=0&motorend=1234.56789"
+        );
+    }
+
+    #[test]
+    fn flight_update_serializes_flattened_details() {
+        let update = FlightUpdate {
+            callsign: Some("D-KABC"),
+            details: FlightDetails {
+                landing_count: Some(2),
+                ..Default::default()
+            },
+        };
+
+        let params = WithAccessToken::new("token", &update);
+        assert_eq!(
+            serde_urlencoded::to_string(params).unwrap(),
+            "accesstoken=token&callsign=D-KABC&landingcount=2"
+        );
+    }
+}
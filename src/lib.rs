@@ -1,20 +1,69 @@
+mod account;
 mod article;
+mod backup;
+mod base_url;
+mod calendar;
 mod client;
+mod date;
 mod error;
+mod flight;
+mod maintenance;
 mod md5;
+mod number;
+mod reservation;
 mod sale;
 mod user;
 mod utils;
-
-pub use crate::client::Client;
+mod voucher;
+mod workhours;
+
+pub use crate::client::{
+    Client, ClientBuilder, FileTokenStore, MemoryTokenStore, RateLimit, RetryPolicy, TokenKey,
+    TokenStore,
+};
+pub use account::{
+    add_booking, edit_booking, get_booking, list_bookings_by_date_range, list_bookings_by_year,
+    list_bookings_today, Booking, BookingUpdate, NewBooking,
+};
 pub use article::{list_articles, Article, Price};
+pub use backup::download_backup;
+#[cfg(feature = "zip")]
+pub use backup::{read_backup_entries, BackupEntry};
+pub use base_url::BaseUrl;
+pub use calendar::{
+    add_appointment, delete_appointment, edit_appointment, list_appointments, list_my_appointments,
+    list_public_appointments, Appointment, AppointmentUpdate, MyAppointment, NewAppointment,
+    PublicAppointment,
+};
+pub use date::{Date, DateTime};
+use error::sign_in_error_for_status;
 pub use error::{Error, Result};
+pub use flight::{
+    add_flight, delete_flight, edit_flight, get_flight, join_tow_flights, list_flights_by_date,
+    list_flights_by_date_range, list_flights_by_plane, list_flights_by_user, list_flights_today,
+    list_modified_flights, list_my_flights, ChargeMode, Flight, FlightDetails, FlightMode,
+    FlightUpdate, InvoiceInfo, LaunchMethod, MotorCounter, NewFlight, StartType,
+};
+pub use maintenance::{get_maintenance_status, MaintenanceStatus};
 use md5::serialize_md5;
-pub use sale::{add_sale, NewSale};
+pub use number::Number;
+pub use reservation::{list_active_reservations, Reservation};
+pub use sale::{
+    add_sale, list_modified_sales, list_sales_by_date, list_sales_by_date_range, list_sales_today,
+    NewSale, Sale,
+};
+use std::borrow::Cow;
 use std::fmt::{Debug, Formatter};
-pub use user::{list_users, Key, User};
-
-pub async fn get_access_token(client: &reqwest::Client) -> Result<String> {
+pub use user::{
+    get_current_user, list_users, CurrentUser, Gender, Key, MailRecipient, Property, User,
+};
+pub use voucher::{add_voucher, list_vouchers, NewVoucher, Voucher, VoucherStatus};
+pub use workhours::{
+    add_work_hours, list_work_hour_categories, list_work_hours, NewWorkHours, WorkHourCategory,
+    WorkHours, WorkHoursStatus,
+};
+
+pub async fn get_access_token(client: &reqwest::Client, base_url: &BaseUrl) -> Result<String> {
     #[derive(Debug, serde::Deserialize)]
     struct Response {
         #[serde(rename = "accesstoken")]
@@ -22,7 +71,7 @@ pub async fn get_access_token(client: &reqwest::Client) -> Result<String> {
     }
 
     let response = client
-        .get("https://www.vereinsflieger.de/interface/rest/auth/accesstoken")
+        .get(base_url.endpoint("auth/accesstoken"))
         .send()
         .await?
         .error_for_status()?;
@@ -32,16 +81,43 @@ pub async fn get_access_token(client: &reqwest::Client) -> Result<String> {
 
 pub async fn authenticate(
     client: &reqwest::Client,
+    base_url: &BaseUrl,
     access_token: &str,
     credentials: &Credentials,
 ) -> Result<()> {
-    let params = WithAccessToken::new(access_token, credentials);
+    #[derive(serde::Serialize)]
+    struct SignIn<'a> {
+        #[serde(flatten)]
+        credentials: &'a Credentials,
+        auth_secret: Option<Cow<'a, str>>,
+    }
 
-    client
-        .post("https://www.vereinsflieger.de/interface/rest/auth/signin")
+    let params = SignIn {
+        credentials,
+        auth_secret: credentials.current_auth_secret()?,
+    };
+    let params = WithAccessToken::new(access_token, &params);
+
+    let response = client
+        .post(base_url.endpoint("auth/signin"))
         .header("Content-Type", "application/x-www-form-urlencoded")
         .body(serde_urlencoded::to_string(params)?)
         .send()
+        .await?;
+
+    sign_in_error_for_status(response).await?;
+
+    Ok(())
+}
+
+pub async fn sign_out(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> Result<()> {
+    client
+        .delete(base_url.endpoint(&format!("auth/signout/{access_token}")))
+        .send()
         .await?
         .error_for_status()?;
 
@@ -87,7 +163,20 @@ pub struct Credentials {
     pub password: String,
 
     /// Zwei-Faktor-Authentifizierung
-    pub auth_secret: Option<String>,
+    #[serde(skip)]
+    pub auth_secret: Option<AuthSecret>,
+}
+
+impl Credentials {
+    /// Returns the two-factor authentication code for the next sign-in.
+    fn current_auth_secret(&self) -> Result<Option<Cow<'_, str>>> {
+        Ok(match &self.auth_secret {
+            None => None,
+            Some(AuthSecret::Code(code)) => Some(Cow::Borrowed(code)),
+            #[cfg(feature = "totp")]
+            Some(AuthSecret::Totp(secret)) => Some(Cow::Owned(generate_totp(secret)?)),
+        })
+    }
 }
 
 impl Debug for Credentials {
@@ -97,7 +186,56 @@ impl Debug for Credentials {
             .field("app_key", &self.app_key)
             .field("username", &self.username)
             .field("password", &"********")
-            .field("auth_secret", &"********")
+            .field("auth_secret", &self.auth_secret)
             .finish()
     }
 }
+
+/// Zwei-Faktor-Authentifizierung
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum AuthSecret {
+    /// Aktueller Code der Zwei-Faktor-Authentifizierung
+    ///
+    /// Da der Code nur kurz gültig ist, funktioniert eine erneute Anmeldung
+    /// nach dem Ablauf des Access Tokens damit in der Regel nicht.
+    Code(String),
+
+    /// Geheimer Schlüssel der Zwei-Faktor-Authentifizierung (Base32)
+    ///
+    /// Der aktuelle Code wird bei jeder Anmeldung automatisch daraus
+    /// erzeugt.
+    #[cfg(feature = "totp")]
+    Totp(String),
+}
+
+impl Debug for AuthSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Code(_) => f.debug_tuple("Code").field(&"********").finish(),
+            #[cfg(feature = "totp")]
+            Self::Totp(_) => f.debug_tuple("Totp").field(&"********").finish(),
+        }
+    }
+}
+
+#[cfg(feature = "totp")]
+fn generate_totp(secret: &str) -> Result<String> {
+    use totp_rs::{Algorithm, Secret, TOTP};
+
+    // Authenticator apps usually display the secret in lowercase groups of
+    // four characters, so normalize it before decoding.
+    let secret: String = secret
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    let secret = Secret::Encoded(secret)
+        .to_bytes()
+        .map_err(|error| Error::TotpFailed(error.to_string()))?;
+
+    TOTP::new_unchecked(Algorithm::SHA1, 6, 1, 30, secret)
+        .generate_current()
+        .map_err(|error| Error::TotpFailed(error.to_string()))
+}
@@ -0,0 +1,74 @@
+use crate::error::error_for_status;
+use crate::{BaseUrl, Error, WithAccessToken};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use reqwest::StatusCode;
+
+/// Characters that have to be percent-encoded in a URL path segment.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// Get the current airframe counters of the aircraft with the given
+/// callsign.
+pub async fn get_maintenance_status(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    callsign: &str,
+) -> crate::Result<MaintenanceStatus> {
+    #[derive(serde::Serialize)]
+    struct Params<'a> {
+        callsign: &'a str,
+    }
+
+    let params = Params { callsign };
+    let params = WithAccessToken::new(access_token, &params);
+
+    let path = format!(
+        "maintenance/airplane/{}",
+        utf8_percent_encode(callsign, PATH_SEGMENT)
+    );
+
+    let response = client
+        .post(base_url.endpoint(&path))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    // The API only documents "400 Bad Request" for this endpoint, which it
+    // returns if there is no aircraft with the given callsign.
+    if response.status() == StatusCode::BAD_REQUEST {
+        return Err(Error::UnknownAircraft(callsign.to_string()));
+    }
+
+    let response = error_for_status(response).await?;
+    let bytes = response.bytes().await?;
+
+    let status =
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(status)
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct MaintenanceStatus {
+    /// Aktueller Motorzählerstand
+    #[serde(rename = "motortime")]
+    pub motor_time: String,
+    /// Aktueller Flugzeitenstand
+    #[serde(rename = "flighttime")]
+    pub flight_time: String,
+    /// Aktuelle Anzahl der Landungen
+    #[serde(rename = "landingcount")]
+    pub landing_count: String,
+}
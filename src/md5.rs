@@ -1,10 +1,14 @@
 use md5::{Digest, Md5};
 use serde::Serializer;
 
-pub fn serialize_md5<S: Serializer>(input: &str, serializer: S) -> Result<S::Ok, S::Error> {
+pub fn md5_hex(input: &str) -> String {
     let mut hasher = Md5::new();
     hasher.update(input.as_bytes());
     let result = hasher.finalize();
 
-    serializer.serialize_str(&format!("{:x}", result))
+    format!("{:x}", result)
+}
+
+pub fn serialize_md5<S: Serializer>(input: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&md5_hex(input))
 }
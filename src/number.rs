@@ -0,0 +1,76 @@
+use serde::{Serialize, Serializer};
+
+/// A number (e.g. a price, a tax rate or an amount) that is sent to the API.
+///
+/// Numbers can either be passed as [`f64`] values, or, if the `rust_decimal`
+/// feature is enabled, as exact `rust_decimal::Decimal` values, which
+/// avoids rounding errors for monetary values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum Number {
+    /// Gleitkommazahl
+    Float(f64),
+    /// Exakte Dezimalzahl
+    #[cfg(feature = "rust_decimal")]
+    Decimal(rust_decimal::Decimal),
+}
+
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for Number {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Self::Decimal(value)
+    }
+}
+
+impl Serialize for Number {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Float(value) => serializer.serialize_f64(*value),
+            // The `Display` implementation of `Decimal` never uses an
+            // exponent and always uses `.` as the decimal separator.
+            #[cfg(feature = "rust_decimal")]
+            Self::Decimal(value) => serializer.collect_str(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Serialize)]
+    struct Params {
+        value: Number,
+    }
+
+    fn to_string(value: impl Into<Number>) -> String {
+        serde_urlencoded::to_string(Params {
+            value: value.into(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(to_string(42.15), "value=42.15");
+        assert_eq!(to_string(19.0), "value=19.0");
+        assert_eq!(to_string(-0.5), "value=-0.5");
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn decimal_keeps_its_scale() {
+        use rust_decimal::Decimal;
+
+        assert_eq!(to_string(Decimal::new(4215, 2)), "value=42.15");
+        assert_eq!(to_string(Decimal::new(4210, 2)), "value=42.10");
+        assert_eq!(to_string(Decimal::new(19, 0)), "value=19");
+        assert_eq!(to_string(Decimal::new(1, 10)), "value=0.0000000001");
+    }
+}
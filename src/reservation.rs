@@ -0,0 +1,112 @@
+use crate::error::error_for_status;
+use crate::utils::list::parse_list;
+use crate::{BaseUrl, WithAccessToken};
+
+/// Get all current reservations.
+pub async fn list_active_reservations(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> crate::Result<Vec<Reservation>> {
+    let params = WithAccessToken::new(access_token, &());
+
+    let response = client
+        .post(base_url.endpoint("reservation/list/active"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    parse_list(&bytes)
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct Reservation {
+    /// ReservierungsId
+    #[serde(rename = "prid")]
+    pub reservation_id: String,
+    /// Beginn der Reservierung
+    #[serde(rename = "datefrom")]
+    pub date_from: String,
+    /// Ende der Reservierung
+    #[serde(rename = "dateto")]
+    pub date_to: String,
+    /// Kommentar
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub comment: String,
+    /// Freie Sitze
+    #[serde(rename = "freeseats")]
+    pub free_seats: String,
+    /// Pilot
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub user: String,
+    /// Fluglehrer
+    #[serde(rename = "fi", deserialize_with = "crate::utils::serde::unescape")]
+    pub flight_instructor: String,
+    /// Art der Reservierung
+    #[serde(rename = "type", deserialize_with = "crate::utils::serde::unescape")]
+    pub reservation_type: String,
+    /// Bezeichnung der Ressource
+    #[serde(
+        rename = "ressource",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub resource: String,
+    /// Zeitraum im Klartext
+    #[serde(rename = "daterange")]
+    pub date_range: String,
+    /// Dauer im Klartext
+    pub duration: String,
+}
+
+#[cfg(feature = "chrono")]
+impl Reservation {
+    /// Returns when the reservation starts.
+    pub fn parsed_date_from(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.date_from)
+    }
+
+    /// Returns when the reserved resource becomes available again.
+    pub fn parsed_date_to(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.date_to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reservation_list_entry() {
+        let json = r#"{
+            "prid": "5001",
+            "datefrom": "2024-07-20 10:00:00",
+            "dateto": "2024-07-20 14:30:00",
+            "comment": "&Uuml;berlandflug",
+            "freeseats": "1",
+            "user": "Mustermann, Max",
+            "fi": "",
+            "type": "Privat",
+            "ressource": "D-KABC (ASK 21)",
+            "daterange": "20.07.2024 10:00 - 14:30",
+            "duration": "4,5 Stunden"
+        }"#;
+
+        let reservation: Reservation = serde_json::from_str(json).unwrap();
+        assert_eq!(reservation.reservation_id, "5001");
+        assert_eq!(reservation.comment, "Überlandflug");
+        assert_eq!(reservation.free_seats, "1");
+        assert_eq!(reservation.reservation_type, "Privat");
+        assert_eq!(reservation.resource, "D-KABC (ASK 21)");
+
+        #[cfg(feature = "chrono")]
+        {
+            let date_from = reservation.parsed_date_from().unwrap().unwrap();
+            assert_eq!(date_from.to_string(), "2024-07-20 10:00:00");
+            let date_to = reservation.parsed_date_to().unwrap().unwrap();
+            assert_eq!(date_to.to_string(), "2024-07-20 14:30:00");
+        }
+    }
+}
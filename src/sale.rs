@@ -1,37 +1,221 @@
 use crate::error::error_for_status;
-use crate::WithAccessToken;
+use crate::utils::list::parse_list;
+use crate::utils::range::check_range;
+use crate::{BaseUrl, Date, Number, WithAccessToken};
 
 pub async fn add_sale(
     client: &reqwest::Client,
+    base_url: &BaseUrl,
     access_token: &str,
     new_sale: &NewSale<'_>,
-) -> crate::Result<()> {
+) -> crate::Result<Sale> {
     let params = WithAccessToken::new(access_token, new_sale);
 
     let response = client
-        .post("https://www.vereinsflieger.de/interface/rest/sale/add")
+        .post(base_url.endpoint("sale/add"))
         .header("Content-Type", "application/x-www-form-urlencoded")
         .body(serde_urlencoded::to_string(params)?)
         .send()
         .await?;
 
-    error_for_status(response).await?;
+    let bytes = error_for_status(response).await?.bytes().await?;
 
-    Ok(())
+    let sale = serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(sale)
+}
+
+/// Get all sales with a service date within the given date range
+/// (`YYYY-mm-dd`).
+pub async fn list_sales_by_date_range(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    date_from: &str,
+    date_to: &str,
+) -> crate::Result<Vec<Sale>> {
+    #[derive(serde::Serialize)]
+    struct Params<'a> {
+        #[serde(rename = "datefrom")]
+        date_from: &'a str,
+        #[serde(rename = "dateto")]
+        date_to: &'a str,
+    }
+
+    let params = Params { date_from, date_to };
+    list_sales(client, base_url, access_token, "daterange", &params).await
+}
+
+/// Get all sales that were modified within the last `days` (1–28) days.
+pub async fn list_modified_sales(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    days: u32,
+) -> crate::Result<Vec<Sale>> {
+    #[derive(serde::Serialize)]
+    struct Params {
+        days: u32,
+    }
+
+    check_range("days", days, 1..=28)?;
+
+    let params = Params { days };
+    list_sales(client, base_url, access_token, "modified", &params).await
+}
+
+/// Get all sales with the given service date (`YYYY-mm-dd`).
+pub async fn list_sales_by_date(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    date: &str,
+) -> crate::Result<Vec<Sale>> {
+    #[derive(serde::Serialize)]
+    struct Params<'a> {
+        date: &'a str,
+    }
+
+    let params = Params { date };
+    list_sales(client, base_url, access_token, "date", &params).await
+}
+
+/// Get all sales with today's service date.
+pub async fn list_sales_today(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> crate::Result<Vec<Sale>> {
+    list_sales(client, base_url, access_token, "today", &()).await
+}
+
+async fn list_sales<T: serde::Serialize>(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    endpoint: &str,
+    params: &T,
+) -> crate::Result<Vec<Sale>> {
+    let params = WithAccessToken::new(access_token, params);
+
+    let response = client
+        .post(base_url.endpoint(&format!("sale/list/{endpoint}")))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    parse_list(&bytes)
+}
+
+/// Verkauf
+///
+/// Die Antwort der API ist nicht dokumentiert. Die Felder entsprechen daher
+/// den Parametern beim Anlegen eines Verkaufs, alle weiteren Felder sind in
+/// [`Sale::other`] enthalten.
+#[derive(Debug, serde::Deserialize)]
+pub struct Sale {
+    /// Datum der Buchung (`YYYY-mm-dd`)
+    #[serde(rename = "bookingdate", default)]
+    pub booking_date: String,
+
+    /// Artikelnummer
+    #[serde(
+        rename = "articleid",
+        default,
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub article_id: String,
+
+    /// Menge
+    #[serde(default)]
+    pub amount: String,
+
+    /// Mitgliedsnummer des Käufers
+    #[serde(rename = "memberid", default)]
+    pub member_id: String,
+
+    /// Callsign bzw. Verwendung
+    #[serde(default, deserialize_with = "crate::utils::serde::unescape")]
+    pub callsign: String,
+
+    /// Steuer
+    #[serde(rename = "salestax", default)]
+    pub sales_tax: String,
+
+    /// Bruttopreis
+    #[serde(rename = "totalprice", default)]
+    pub total_price: String,
+
+    /// Zählerstand
+    #[serde(default)]
+    pub counter: String,
+
+    /// Kommentar
+    #[serde(default, deserialize_with = "crate::utils::serde::unescape")]
+    pub comment: String,
+
+    /// Gebührenbereich
+    #[serde(
+        rename = "costtype",
+        default,
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub cost_type: String,
+
+    /// id des Habenkontos
+    #[serde(default)]
+    pub caid2: String,
+
+    /// Sphäre
+    #[serde(rename = "spid", default)]
+    pub sphere: String,
+
+    /// Weitere, nicht dokumentierte Felder
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(feature = "chrono")]
+impl Sale {
+    /// Returns the day the sale was booked.
+    pub fn parsed_booking_date(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.booking_date)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Sale {
+    /// Returns the sold quantity of the article.
+    pub fn parsed_amount(&self) -> crate::Result<Option<rust_decimal::Decimal>> {
+        crate::utils::decimal::parse_decimal(&self.amount)
+    }
+
+    /// Returns the sales tax rate in percent.
+    pub fn parsed_sales_tax(&self) -> crate::Result<Option<rust_decimal::Decimal>> {
+        crate::utils::decimal::parse_decimal(&self.sales_tax)
+    }
+
+    /// Returns the gross price of the whole sale.
+    pub fn parsed_total_price(&self) -> crate::Result<Option<rust_decimal::Decimal>> {
+        crate::utils::decimal::parse_decimal(&self.total_price)
+    }
 }
 
 #[derive(Debug, serde::Serialize)]
 pub struct NewSale<'a> {
     /// Datum der Buchung (`YYYY-mm-dd`)
     #[serde(rename = "bookingdate")]
-    pub booking_date: &'a str,
+    pub booking_date: Date<'a>,
 
     /// Artikelnummer
     #[serde(rename = "articleid")]
     pub article_id: &'a str,
 
     /// Menge
-    pub amount: f64,
+    pub amount: Number,
 
     /// Mitgliedsnummer des Käufers
     #[serde(rename = "memberid")]
@@ -42,14 +226,14 @@ pub struct NewSale<'a> {
 
     /// Steuer
     #[serde(rename = "salestax")]
-    pub sales_tax: Option<f64>,
+    pub sales_tax: Option<Number>,
 
     /// Bruttopreis
     #[serde(rename = "totalprice")]
-    pub total_price: Option<f64>,
+    pub total_price: Option<Number>,
 
     /// Zählerstand
-    pub counter: Option<f64>,
+    pub counter: Option<Number>,
 
     /// Kommentar
     pub comment: Option<&'a str>,
@@ -64,3 +248,71 @@ pub struct NewSale<'a> {
     /// Sphäre
     pub spid: Option<u32>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn added_sale() {
+        let json = r#"{
+            "said": "98765",
+            "bookingdate": "2025-01-14",
+            "articleid": "Lebensmittel",
+            "amount": "3.00",
+            "memberid": "11011",
+            "callsign": "ClubFridge neo (5x Weizen &amp; 2x Chips)",
+            "salestax": "19.00",
+            "totalprice": "42.15",
+            "counter": "0.00",
+            "comment": "",
+            "costtype": "Theke",
+            "caid2": "8400",
+            "spid": "1"
+        }"#;
+
+        let sale: Sale = serde_json::from_str(json).unwrap();
+        assert_eq!(sale.booking_date, "2025-01-14");
+        assert_eq!(sale.article_id, "Lebensmittel");
+        assert_eq!(sale.amount, "3.00");
+        assert_eq!(sale.member_id, "11011");
+        assert_eq!(sale.callsign, "ClubFridge neo (5x Weizen & 2x Chips)");
+        assert_eq!(sale.sales_tax, "19.00");
+        assert_eq!(sale.total_price, "42.15");
+        assert_eq!(sale.counter, "0.00");
+        assert_eq!(sale.comment, "");
+        assert_eq!(sale.cost_type, "Theke");
+        assert_eq!(sale.caid2, "8400");
+        assert_eq!(sale.sphere, "1");
+
+        // Only the undocumented fields end up in `other`, so a misspelled
+        // field name would show up here.
+        let other: Vec<_> = sale.other.keys().collect();
+        assert_eq!(other, ["said"]);
+    }
+
+    #[test]
+    fn new_sale() {
+        let sale = NewSale {
+            booking_date: "2025-01-14".into(),
+            article_id: "Lebensmittel",
+            amount: 3.0.into(),
+            member_id: Some(11011),
+            callsign: None,
+            sales_tax: None,
+            total_price: Some(42.15.into()),
+            counter: None,
+            comment: None,
+            cost_type: Some("Theke"),
+            caid2: None,
+            spid: None,
+        };
+
+        let params = WithAccessToken::new("token", &sale);
+        assert_eq!(
+            serde_urlencoded::to_string(params).unwrap(),
+            "accesstoken=token&bookingdate=2025-01-14&articleid=Lebensmittel&amount=3.0\
+             &memberid=11011&totalprice=42.15&costtype=Theke"
+        );
+    }
+}
@@ -1,11 +1,17 @@
 use crate::error::error_for_status;
-use crate::WithAccessToken;
+use crate::{BaseUrl, WithAccessToken};
+use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
 
-pub async fn list_users(client: &reqwest::Client, access_token: &str) -> crate::Result<Vec<User>> {
+pub async fn list_users(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> crate::Result<Vec<User>> {
     let params = WithAccessToken::new(access_token, &());
 
     let response = client
-        .post("https://www.vereinsflieger.de/interface/rest/user/list")
+        .post(base_url.endpoint("user/list"))
         .header("Content-Type", "application/x-www-form-urlencoded")
         .body(serde_urlencoded::to_string(params)?)
         .send()
@@ -13,14 +19,28 @@ pub async fn list_users(client: &reqwest::Client, access_token: &str) -> crate::
 
     let bytes = error_for_status(response).await?.bytes().await?;
 
-    let json: serde_json::Map<String, serde_json::Value> =
-        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+    crate::utils::list::parse_list(&bytes)
+}
+
+pub async fn get_current_user(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> crate::Result<CurrentUser> {
+    let params = WithAccessToken::new(access_token, &());
 
-    json.into_iter()
-        .filter(|(k, _)| k.parse::<usize>().is_ok())
-        .map(|(_, v)| serde_path_to_error::deserialize(v))
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(Into::into)
+    let response = client
+        .post(base_url.endpoint("auth/getuser"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    let user = serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(user)
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -47,7 +67,7 @@ pub struct User {
     #[serde(deserialize_with = "crate::utils::serde::unescape")]
     pub nickname: String,
     /// Geschlecht (m, w)
-    pub gender: String,
+    pub gender: Gender,
 
     /// Straße
     #[serde(deserialize_with = "crate::utils::serde::unescape")]
@@ -136,7 +156,7 @@ pub struct User {
     /// Ausstrittsdatum
     #[serde(rename = "memberend")]
     pub member_end: String,
-    /// Mitgliedsstatus
+    /// Mitgliedsstatus (vom Verein festgelegt, z.B. "Aktiv" oder "Passiv")
     #[serde(
         rename = "memberstatus",
         deserialize_with = "crate::utils::serde::unescape"
@@ -150,7 +170,40 @@ pub struct User {
     )]
     pub letter_title: String,
     /// Rundmailempfänger
-    pub mailrecipient: String,
+    pub mailrecipient: MailRecipient,
+
+    /// Kontoinhaber
+    #[cfg(feature = "banking")]
+    #[serde(
+        default,
+        rename = "bankaccountname",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub bank_account_name: String,
+    /// Lastschrift
+    #[cfg(feature = "banking")]
+    #[serde(
+        default,
+        rename = "directdebitauth",
+        deserialize_with = "crate::utils::serde::flag"
+    )]
+    pub direct_debit_auth: bool,
+    /// IBAN
+    #[cfg(feature = "banking")]
+    #[serde(default, deserialize_with = "crate::utils::serde::unescape")]
+    pub iban: String,
+    /// BIC
+    #[cfg(feature = "banking")]
+    #[serde(default, deserialize_with = "crate::utils::serde::unescape")]
+    pub bic: String,
+    /// Mandat
+    #[cfg(feature = "banking")]
+    #[serde(default, deserialize_with = "crate::utils::serde::unescape")]
+    pub mandate: String,
+    /// Mandatsdatum
+    #[cfg(feature = "banking")]
+    #[serde(default, rename = "mandatedate")]
+    pub mandate_date: String,
 
     /// Nicht beendete Lehrpläne
     #[serde(deserialize_with = "crate::utils::serde::unescape_vec")]
@@ -168,6 +221,169 @@ pub struct User {
 
     /// Liste der zugeordneten Schlüssel
     pub keymanagement: Vec<Key>,
+
+    /// Liste der Sachkonten, jeweils mit ihren Feldern nach Namen
+    ///
+    /// The API specification only documents this as an array, without the
+    /// fields of its entries, so they are kept as maps instead of guessing a
+    /// struct that might silently miss fields.
+    #[serde(default, deserialize_with = "deserialize_entries")]
+    pub accounts: Vec<BTreeMap<String, String>>,
+
+    /// Mitgliedsstatus im Landesverband, jeweils mit den Feldern nach Namen
+    ///
+    /// Like [`accounts`](Self::accounts), the fields of these entries are not
+    /// documented, so they are kept as maps.
+    #[serde(
+        default,
+        rename = "stateassociation",
+        deserialize_with = "deserialize_entries"
+    )]
+    pub state_association: Vec<BTreeMap<String, String>>,
+
+    /// Weitere Eigenschaften (`prop(x)`), nach ihrem internen Index
+    #[serde(flatten, deserialize_with = "deserialize_properties")]
+    pub properties: BTreeMap<u32, Property>,
+}
+
+#[cfg(feature = "chrono")]
+impl User {
+    /// Returns the date of birth.
+    pub fn parsed_birthday(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.birthday)
+    }
+
+    /// Returns the day the member joined the club.
+    pub fn parsed_member_begin(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.member_begin)
+    }
+
+    /// Returns the day the member left (or will leave) the club, or `None`
+    /// for current members.
+    pub fn parsed_member_end(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.member_end)
+    }
+
+    /// Returns the expiry of the police clearance certificate, or `None` if
+    /// there is none.
+    pub fn parsed_policecert_validto(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.policecert_validto)
+    }
+
+    /// Returns the day the direct debit mandate was signed, or `None` if
+    /// there is no mandate.
+    #[cfg(feature = "banking")]
+    pub fn parsed_mandate_date(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.mandate_date)
+    }
+}
+
+/// Eine weitere Eigenschaft eines Mitglieds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Property {
+    /// Bezeichnung der Eigenschaft
+    pub label: String,
+    /// Wert der Eigenschaft
+    pub value: String,
+}
+
+/// Collect the `prop(x)` fields of a user into a map of [`Property`]s.
+///
+/// The API returns each property as an array (or object) with the label and
+/// the value of the property, in that order. All other fields are ignored.
+fn deserialize_properties<'de, D>(deserializer: D) -> Result<BTreeMap<u32, Property>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let fields = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+
+    let properties = fields
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let index = key.strip_prefix("prop")?.parse::<u32>().ok()?;
+
+            let mut values = match value {
+                serde_json::Value::Array(values) => values.into_iter(),
+                serde_json::Value::Object(map) => map
+                    .into_iter()
+                    .map(|(_, v)| v)
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+                _ => return None,
+            };
+
+            let label = to_string(values.next().unwrap_or_default());
+            let value = to_string(values.next().unwrap_or_default());
+
+            Some((index, Property { label, value }))
+        })
+        .collect();
+
+    Ok(properties)
+}
+
+/// Collect the entries of a list field like `accounts` into maps of their
+/// fields.
+///
+/// The API returns these lists either as arrays or as objects with the index
+/// as key, and as an empty string if there are no entries. Entries that are
+/// not objects are ignored.
+fn deserialize_entries<'de, D>(deserializer: D) -> Result<Vec<BTreeMap<String, String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let entries = match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Array(entries) => entries,
+        serde_json::Value::Object(map) => map.into_iter().map(|(_, v)| v).collect(),
+        _ => return Ok(Vec::new()),
+    };
+
+    let entries = entries
+        .into_iter()
+        .filter_map(|entry| match entry {
+            serde_json::Value::Object(fields) => Some(
+                fields
+                    .into_iter()
+                    .map(|(key, value)| (key, to_string(value)))
+                    .collect(),
+            ),
+            _ => None,
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+fn to_string(value: serde_json::Value) -> String {
+    let string = match value {
+        serde_json::Value::Null => return String::new(),
+        serde_json::Value::String(string) => string,
+        value => value.to_string(),
+    };
+
+    html_escape::decode_html_entities(&string).into_owned()
+}
+
+crate::utils::serde::code_enum! {
+    /// Geschlecht
+    pub enum Gender {
+        /// Männlich (`m`)
+        Male = "m",
+        /// Weiblich (`w`)
+        Female = "w",
+        /// Divers (`d`)
+        Diverse = "d",
+    }
+}
+
+crate::utils::serde::code_enum! {
+    /// Rundmailempfänger
+    pub enum MailRecipient {
+        /// Ja (`1`)
+        Yes = "1",
+        /// Nein (`0`)
+        No = "0",
+    }
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -177,3 +393,206 @@ pub struct Key {
     #[serde(rename = "keyname", deserialize_with = "crate::utils::serde::unescape")]
     pub name: String,
 }
+
+/// Der angemeldete Benutzer
+#[derive(Debug, serde::Deserialize)]
+pub struct CurrentUser {
+    /// Eindeutige Id im Vereinsflieger
+    #[serde(rename = "uid")]
+    pub user_id: String,
+    /// Vorname
+    #[serde(
+        rename = "firstname",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub first_name: String,
+    /// Nachname
+    #[serde(
+        rename = "lastname",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub last_name: String,
+    /// Mitgliedsnummer
+    #[serde(rename = "memberid")]
+    pub member_id: String,
+    /// Mitgliedsstatus
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub status: String,
+    /// Zugeordnete Rollen
+    #[serde(deserialize_with = "crate::utils::serde::unescape_vec")]
+    pub roles: Vec<String>,
+    /// E-Mail-Adresse
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub email: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const USER: &str = r#"{
+        "uid": "1234",
+        "title": "Dr.",
+        "firstname": "Max",
+        "lastname": "M&uuml;ller",
+        "nickname": "",
+        "gender": "m",
+        "street": "Flugplatzstra&szlig;e 1",
+        "postofficebox": "",
+        "careof": "",
+        "zipcode": "12345",
+        "town": "Musterstadt",
+        "country": "Deutschland",
+        "birthday": "01.02.1980",
+        "birthplace": "Musterstadt",
+        "email": "max@example.com",
+        "homenumber": "",
+        "mobilenumber": "0170 1234567",
+        "phonenumber": "",
+        "phonenumber2": "",
+        "carlicenseplate": "",
+        "identification": "",
+        "natoid": "",
+        "policecert_validto": "0000-00-00",
+        "ice_contact1": "",
+        "ice_contact2": "",
+        "memberid": "42",
+        "memberbegin": "01.04.2001",
+        "memberend": "",
+        "memberstatus": "Aktiv",
+        "lettertitle": "Lieber Max",
+        "mailrecipient": 1,
+        "bankaccountname": "Max M&uuml;ller",
+        "directdebitauth": 1,
+        "iban": "DE02120300000000202051",
+        "bic": "BYLADEM1001",
+        "mandate": "M-42",
+        "mandatedate": "2020-01-15",
+        "educations": ["PPL(A)"],
+        "roles": ["Mitglied"],
+        "sector": ["Segelflug"],
+        "functions": [],
+        "keymanagement": [{ "title": "Halle", "keyname": "H1" }],
+        "accounts": [{ "accountno": "10042", "name": "Mitgliedskonto" }],
+        "stateassociation": [],
+        "prop3": ["Spind", "12"],
+        "prop7": ["Ehrenmitglied", "nein"]
+    }"#;
+
+    fn user() -> serde_json::Value {
+        serde_json::from_str(USER).unwrap()
+    }
+
+    #[test]
+    fn user_list_entry() {
+        let user: User = serde_json::from_str(USER).unwrap();
+
+        assert_eq!(user.last_name, "Müller");
+        assert_eq!(user.gender, Gender::Male);
+        assert_eq!(user.mailrecipient, MailRecipient::Yes);
+        assert_eq!(user.keymanagement[0].name, "H1");
+        assert_eq!(user.accounts[0]["accountno"], "10042");
+        assert!(user.state_association.is_empty());
+
+        // Only the `prop(x)` fields end up in the properties, regardless of
+        // whether the banking fields are deserialized or not.
+        assert_eq!(user.properties.keys().collect::<Vec<_>>(), [&3, &7]);
+        assert_eq!(user.properties[&3].label, "Spind");
+        assert_eq!(user.properties[&7].value, "nein");
+
+        #[cfg(feature = "banking")]
+        {
+            assert_eq!(user.bank_account_name, "Max Müller");
+            assert!(user.direct_debit_auth);
+            assert_eq!(user.iban, "DE02120300000000202051");
+            assert_eq!(user.mandate_date, "2020-01-15");
+        }
+    }
+
+    #[test]
+    fn user_list_entry_without_banking_fields() {
+        let mut user = user();
+        let fields = user.as_object_mut().unwrap();
+        for key in [
+            "bankaccountname",
+            "directdebitauth",
+            "iban",
+            "bic",
+            "mandate",
+            "mandatedate",
+        ] {
+            fields.remove(key);
+        }
+
+        let user: User = serde_json::from_value(user).unwrap();
+        assert_eq!(user.properties.len(), 2);
+
+        #[cfg(feature = "banking")]
+        {
+            assert_eq!(user.iban, "");
+            assert!(!user.direct_debit_auth);
+        }
+    }
+
+    #[test]
+    fn properties_from_arrays() {
+        let fields = json!({
+            "uid": "1",
+            "prop2": ["Spind", "12"],
+            "prop10": ["Ehrenmitglied", null],
+        });
+
+        let properties = deserialize_properties(fields).unwrap();
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties[&2].label, "Spind");
+        assert_eq!(properties[&2].value, "12");
+        assert_eq!(properties[&10].label, "Ehrenmitglied");
+        assert_eq!(properties[&10].value, "");
+    }
+
+    #[test]
+    fn properties_from_objects() {
+        let fields = json!({
+            "prop1": { "0": "Gr&ouml;&szlig;e", "1": 180 },
+            "propeller": "ignored",
+        });
+
+        let properties = deserialize_properties(fields).unwrap();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(properties[&1].label, "Größe");
+        assert_eq!(properties[&1].value, "180");
+    }
+
+    #[test]
+    fn codes() {
+        let gender: Gender = serde_json::from_value(json!("w")).unwrap();
+        assert_eq!(gender, Gender::Female);
+
+        let mailrecipient: MailRecipient = serde_json::from_value(json!(1)).unwrap();
+        assert_eq!(mailrecipient, MailRecipient::Yes);
+        let mailrecipient: MailRecipient = serde_json::from_value(json!("0")).unwrap();
+        assert_eq!(mailrecipient, MailRecipient::No);
+
+        let gender: Gender = serde_json::from_value(json!("x")).unwrap();
+        assert_eq!(gender, Gender::Other("x".to_string()));
+        assert_eq!(gender.as_str(), "x");
+        assert_eq!(serde_json::to_value(&gender).unwrap(), json!("x"));
+    }
+
+    #[test]
+    fn entries_from_arrays_and_objects() {
+        let array = json!([{ "accountno": 1200, "name": "M&uuml;ller" }]);
+        let entries = deserialize_entries(array).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["accountno"], "1200");
+        assert_eq!(entries[0]["name"], "Müller");
+
+        let object = json!({ "0": { "name": "LV" }, "1": { "name": "DAeC" } });
+        let entries = deserialize_entries(object).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1]["name"], "DAeC");
+
+        assert!(deserialize_entries(json!("")).unwrap().is_empty());
+    }
+}
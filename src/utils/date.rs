@@ -0,0 +1,128 @@
+use crate::Error;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Parse a date as returned by the API.
+///
+/// The API uses both `YYYY-MM-DD` and `dd.mm.yyyy` for dates, optionally
+/// followed by a time, which is ignored. Empty values and placeholders like
+/// `0000-00-00` are returned as `None`, anything else that is not a valid
+/// date as [`Error::InvalidDate`].
+pub fn parse_date(value: &str) -> crate::Result<Option<NaiveDate>> {
+    let Some(date) = value.split_whitespace().next() else {
+        return Ok(None);
+    };
+
+    parse_date_part(date).ok_or_else(|| Error::InvalidDate(value.to_string()))
+}
+
+/// Parse a date and time as returned by the API, e.g. `YYYY-MM-DD hh:mm` or
+/// `YYYY-MM-DD hh:mm:ss`.
+///
+/// Like [`parse_date()`], empty values and placeholders like
+/// `0000-00-00 00:00:00` are returned as `None`. A date without a time is
+/// returned as [`Error::InvalidDate`].
+pub fn parse_datetime(value: &str) -> crate::Result<Option<NaiveDateTime>> {
+    let invalid = || Error::InvalidDate(value.to_string());
+
+    let mut parts = value.split_whitespace();
+    let Some(date) = parts.next() else {
+        return Ok(None);
+    };
+
+    let Some(date) = parse_date_part(date).ok_or_else(invalid)? else {
+        return Ok(None);
+    };
+
+    let time = match (parts.next(), parts.next()) {
+        (Some(time), None) => parse_time_part(time).ok_or_else(invalid)?,
+        _ => return Err(invalid()),
+    };
+
+    Ok(Some(date.and_time(time)))
+}
+
+/// Returns `Some(None)` for placeholders that consist only of zeros, and
+/// `None` if the date is invalid.
+fn parse_date_part(date: &str) -> Option<Option<NaiveDate>> {
+    let (year, month, day) = if let Some((year, rest)) = date.split_once('-') {
+        let (month, day) = rest.split_once('-')?;
+        (year, month, day)
+    } else {
+        let (day, rest) = date.split_once('.')?;
+        let (month, year) = rest.split_once('.')?;
+        (year, month, day)
+    };
+
+    let (year, month, day) = (year.parse().ok()?, month.parse().ok()?, day.parse().ok()?);
+    if (year, month, day) == (0, 0, 0) {
+        return Some(None);
+    }
+
+    NaiveDate::from_ymd_opt(year, month, day).map(Some)
+}
+
+fn parse_time_part(time: &str) -> Option<NaiveTime> {
+    let mut parts = time.split(':');
+    let hour = parts.next()?.parse().ok()?;
+    let minute = parts.next()?.parse().ok()?;
+    let second = parts.next().map_or(Some(0), |second| second.parse().ok())?;
+    if parts.next().is_some() {
+        return None;
+    }
+
+    NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(parse_date("2024-03-05").unwrap(), Some(date(2024, 3, 5)));
+        assert_eq!(parse_date("05.03.2024").unwrap(), Some(date(2024, 3, 5)));
+        assert_eq!(
+            parse_date("2024-03-05 14:30").unwrap(),
+            Some(date(2024, 3, 5))
+        );
+    }
+
+    #[test]
+    fn missing_dates() {
+        assert_eq!(parse_date("").unwrap(), None);
+        assert_eq!(parse_date("  ").unwrap(), None);
+        assert_eq!(parse_date("0000-00-00").unwrap(), None);
+        assert_eq!(parse_date("00.00.0000").unwrap(), None);
+        assert_eq!(parse_date("0000-00-00 00:00:00").unwrap(), None);
+    }
+
+    #[test]
+    fn invalid_dates() {
+        for value in ["2024-02-30", "05/03/2024", "2024-03", "gestern"] {
+            let error = parse_date(value).unwrap_err();
+            assert!(matches!(error, Error::InvalidDate(v) if v == value));
+        }
+    }
+
+    #[test]
+    fn datetimes() {
+        let expected = date(2024, 3, 5).and_hms_opt(14, 30, 0).unwrap();
+        assert_eq!(parse_datetime("2024-03-05 14:30").unwrap(), Some(expected));
+        assert_eq!(
+            parse_datetime("2024-03-05 14:30:00").unwrap(),
+            Some(expected)
+        );
+        assert_eq!(parse_datetime("05.03.2024 14:30").unwrap(), Some(expected));
+
+        assert_eq!(parse_datetime("").unwrap(), None);
+        assert_eq!(parse_datetime("0000-00-00 00:00:00").unwrap(), None);
+
+        for value in ["2024-03-05", "2024-03-05 25:00", "2024-03-05 14:30 UTC"] {
+            assert!(parse_datetime(value).is_err(), "{value}");
+        }
+    }
+}
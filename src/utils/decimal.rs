@@ -0,0 +1,83 @@
+use crate::Error;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Parse a decimal number as returned by the API.
+///
+/// Both `.` and `,` are accepted as the decimal separator. If `,` is used,
+/// `.` is accepted as the thousands separator (e.g. `1.234,56`), but only
+/// between groups of three digits. Empty values are returned as `None`,
+/// anything else that is not a valid number as [`Error::InvalidNumber`].
+pub fn parse_decimal(value: &str) -> crate::Result<Option<Decimal>> {
+    let invalid = || Error::InvalidNumber(value.to_string());
+
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+
+    let normalized = match trimmed.split_once(',') {
+        Some((integer, fraction)) => {
+            let mut groups = integer.split('.');
+            let first = groups.next().unwrap_or_default();
+            let mut integer = first.to_string();
+            for group in groups {
+                if group.len() != 3 || !group.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                integer.push_str(group);
+            }
+
+            if fraction.contains(['.', ',']) {
+                return Err(invalid());
+            }
+
+            format!("{integer}.{fraction}")
+        }
+        None => trimmed.to_string(),
+    };
+
+    Decimal::from_str(&normalized)
+        .map(Some)
+        .map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(value: &str) -> Option<Decimal> {
+        Some(Decimal::from_str(value).unwrap())
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_decimal("12.50").unwrap(), decimal("12.50"));
+        assert_eq!(parse_decimal("12,50").unwrap(), decimal("12.50"));
+        assert_eq!(parse_decimal("-7").unwrap(), decimal("-7"));
+        assert_eq!(parse_decimal(" 19 ").unwrap(), decimal("19"));
+    }
+
+    #[test]
+    fn thousands_separators() {
+        assert_eq!(parse_decimal("1.234,56").unwrap(), decimal("1234.56"));
+        assert_eq!(
+            parse_decimal("-1.234.567,8").unwrap(),
+            decimal("-1234567.8")
+        );
+    }
+
+    #[test]
+    fn missing_numbers() {
+        assert_eq!(parse_decimal("").unwrap(), None);
+        assert_eq!(parse_decimal("  ").unwrap(), None);
+    }
+
+    #[test]
+    fn invalid_numbers() {
+        for value in ["abc", "1.234.567", "1,234.56", "12.34,5", "1,2,3"] {
+            let error = parse_decimal(value).unwrap_err();
+            assert!(matches!(error, Error::InvalidNumber(v) if v == value));
+        }
+    }
+}
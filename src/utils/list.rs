@@ -0,0 +1,50 @@
+use serde::de::DeserializeOwned;
+
+/// Parse a list response of the API.
+///
+/// The API returns lists as a JSON object with numeric keys (`"0"`, `"1"`,
+/// …) for the individual entries, plus some additional metadata like the
+/// `httpstatuscode`, which is ignored here.
+pub fn parse_list<T: DeserializeOwned>(bytes: &[u8]) -> crate::Result<Vec<T>> {
+    let json: serde_json::Map<String, serde_json::Value> =
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(bytes))?;
+
+    json.into_iter()
+        .filter(|(k, _)| k.parse::<usize>().is_ok())
+        .map(|(_, v)| serde_path_to_error::deserialize(v).map_err(crate::Error::from))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Entry {
+        name: String,
+    }
+
+    #[test]
+    fn parses_numeric_keys_in_order() {
+        let json = br#"{"0":{"name":"a"},"1":{"name":"b"},"10":{"name":"c"},"httpstatuscode":200}"#;
+
+        let entries: Vec<Entry> = parse_list(json).unwrap();
+        let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn parses_empty_list() {
+        let entries: Vec<Entry> = parse_list(br#"{"httpstatuscode":200}"#).unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn reports_invalid_entries() {
+        let result = parse_list::<Entry>(br#"{"0":{"name":1}}"#);
+        assert!(matches!(
+            result,
+            Err(crate::Error::DeserializationFailed(_))
+        ));
+    }
+}
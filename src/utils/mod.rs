@@ -1 +1,7 @@
+#[cfg(feature = "chrono")]
+pub mod date;
+#[cfg(feature = "rust_decimal")]
+pub mod decimal;
+pub mod list;
+pub mod range;
 pub mod serde;
@@ -0,0 +1,21 @@
+use crate::Error;
+use std::ops::RangeInclusive;
+
+/// Ensure that a request parameter is within the range documented by the
+/// API, before sending the request.
+pub fn check_range(
+    name: &'static str,
+    value: u32,
+    range: RangeInclusive<u32>,
+) -> crate::Result<()> {
+    if range.contains(&value) {
+        Ok(())
+    } else {
+        Err(Error::OutOfRange {
+            name,
+            value,
+            min: *range.start(),
+            max: *range.end(),
+        })
+    }
+}
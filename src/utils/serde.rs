@@ -1,5 +1,5 @@
 use html_escape::decode_html_entities;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serializer};
 use std::borrow::Cow;
 
 pub fn unescape<'de, D>(deserializer: D) -> Result<String, D::Error>
@@ -18,3 +18,106 @@ where
         .map(|cow| decode_html_entities(&cow).into_owned())
         .collect())
 }
+
+/// Deserialize a flag that the API returns as `1`/`0`, `"1"`/`"0"` or as a
+/// boolean.
+pub fn flag<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Bool(value) => value,
+        serde_json::Value::Number(value) => value.as_u64() == Some(1),
+        serde_json::Value::String(value) => value == "1",
+        _ => false,
+    })
+}
+
+/// Serialize a flag as `1` or `0`, like the API expects it.
+pub fn serialize_flag<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u8(u8::from(*value))
+}
+
+/// Serialize an optional flag as `1` or `0`, or skip it if it is not set.
+pub fn serialize_optional_flag<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_some(&u8::from(*value)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// A code that the API returns either as a string or as a number.
+///
+/// This is used as an intermediate type by the `Deserialize` implementation
+/// of the enums of [`code_enum!`], and is not part of the public API.
+#[derive(Debug)]
+pub(crate) struct Code(pub(crate) String);
+
+impl<'de> Deserialize<'de> for Code {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Code(match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Null => String::new(),
+            serde_json::Value::String(value) => value,
+            value => value.to_string(),
+        }))
+    }
+}
+
+/// Define an enum for the codes that the API uses for a field, with an
+/// `Other` variant that keeps unknown codes.
+///
+/// The enum can be deserialized from string and number codes, is serialized
+/// as the code string, and has an `as_str()` method to get the code.
+macro_rules! code_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $code:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// Unbekannter Wert
+            Other(String),
+        }
+
+        impl $name {
+            /// Returns the code that the API uses for this value.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::Other(value) => value,
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let crate::utils::serde::Code(code) = serde::Deserialize::deserialize(deserializer)?;
+                Ok(match code.as_str() {
+                    $($code => Self::$variant,)*
+                    _ => Self::Other(code),
+                })
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+    };
+}
+
+pub(crate) use code_enum;
@@ -0,0 +1,320 @@
+use crate::error::error_for_status;
+use crate::utils::list::parse_list;
+use crate::{BaseUrl, Date, Gender, Number, WithAccessToken};
+
+pub async fn list_vouchers(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> crate::Result<Vec<Voucher>> {
+    let params = WithAccessToken::new(access_token, &());
+
+    let response = client
+        .post(base_url.endpoint("voucher/list"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    parse_list(&bytes)
+}
+
+pub async fn add_voucher(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    new_voucher: &NewVoucher<'_>,
+) -> crate::Result<Voucher> {
+    let params = WithAccessToken::new(access_token, new_voucher);
+
+    let response = client
+        .post(base_url.endpoint("voucher/add"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    let voucher =
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(voucher)
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct Voucher {
+    /// Eindeutige Id
+    pub vid: String,
+    /// Gutscheinnummer
+    #[serde(
+        rename = "voucherid",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub voucher_id: String,
+    /// Titel des Gutscheins
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub title: String,
+    /// Erstellungsdatum
+    #[serde(rename = "createtime")]
+    pub create_time: String,
+    /// Betrag
+    pub value: String,
+    /// Kommentar
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub comment: String,
+    /// Ausstellungsdatum
+    #[serde(rename = "voucherdate")]
+    pub voucher_date: String,
+    /// Ablaufdatum
+    #[serde(rename = "expiredate")]
+    pub expire_date: String,
+
+    /// Geschlecht (m, w, d)
+    pub gender: Gender,
+    /// Vorname
+    #[serde(
+        rename = "firstname",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub first_name: String,
+    /// Nachname
+    #[serde(
+        rename = "lastname",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub last_name: String,
+    /// Straße
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub street: String,
+    /// Postleitzahl
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub zipcode: String,
+    /// Ort
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub town: String,
+    /// Mailadresse
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub email: String,
+    /// Telefonnummer
+    #[serde(
+        rename = "phonenumber",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub phone_number: String,
+
+    /// Gast/Passagier
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub passenger: String,
+    /// Status
+    #[serde(
+        rename = "statustext",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub status_text: String,
+}
+
+#[cfg(feature = "chrono")]
+impl Voucher {
+    /// Returns the day the voucher was issued.
+    pub fn parsed_voucher_date(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.voucher_date)
+    }
+
+    /// Returns the last day the voucher can be redeemed, or `None` if it
+    /// does not expire.
+    pub fn parsed_expire_date(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.expire_date)
+    }
+
+    /// Returns when the voucher was created in Vereinsflieger.
+    pub fn parsed_create_time(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.create_time)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Voucher {
+    /// Returns the amount the voucher is worth.
+    pub fn parsed_value(&self) -> crate::Result<Option<rust_decimal::Decimal>> {
+        crate::utils::decimal::parse_decimal(&self.value)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct NewVoucher<'a> {
+    /// Gutscheinnummer
+    #[serde(rename = "voucherid")]
+    pub voucher_id: &'a str,
+
+    /// Titel des Gutscheins
+    pub title: &'a str,
+
+    /// Betrag
+    pub value: Number,
+
+    /// Als Person in die Mitgliederverwaltung aufnehmen
+    #[serde(
+        rename = "insertnewuser",
+        serialize_with = "crate::utils::serde::serialize_flag"
+    )]
+    pub insert_new_user: bool,
+
+    /// Kommentar
+    pub comment: Option<&'a str>,
+
+    /// Ausstellungsdatum (`YYYY-mm-dd`)
+    #[serde(rename = "voucherdate")]
+    pub voucher_date: Option<Date<'a>>,
+
+    /// Ablaufdatum (`YYYY-mm-dd`)
+    #[serde(rename = "expiredate")]
+    pub expire_date: Option<Date<'a>>,
+
+    /// Geschlecht (`m`, `w` oder `d`)
+    pub gender: Option<Gender>,
+
+    /// Vorname
+    #[serde(rename = "firstname")]
+    pub first_name: Option<&'a str>,
+
+    /// Nachname
+    #[serde(rename = "lastname")]
+    pub last_name: Option<&'a str>,
+
+    /// Straße
+    pub street: Option<&'a str>,
+
+    /// Postleitzahl
+    pub zipcode: Option<&'a str>,
+
+    /// Ort
+    pub town: Option<&'a str>,
+
+    /// Mailadresse
+    pub email: Option<&'a str>,
+
+    /// Telefonnummer
+    #[serde(rename = "phonenumber")]
+    pub phone_number: Option<&'a str>,
+
+    /// Gast/Passagier
+    pub passenger: Option<&'a str>,
+
+    /// Status
+    pub status: Option<VoucherStatus>,
+}
+
+crate::utils::serde::code_enum! {
+    /// Status eines Gutscheins
+    pub enum VoucherStatus {
+        /// Erstellt (`1`)
+        Created = "1",
+        /// Aktiviert (`2`)
+        Activated = "2",
+        /// Eingelöst (`3`)
+        Redeemed = "3",
+        /// Abgelaufen (`4`)
+        Expired = "4",
+        /// Storniert (`5`)
+        Cancelled = "5",
+        /// Teilweise eingelöst (`6`)
+        PartiallyRedeemed = "6",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn voucher_list_entry() {
+        let json = r#"{
+            "vid": "42",
+            "voucherid": "GS-2024/007",
+            "title": "Rundflug &quot;Alpen&quot;",
+            "createtime": "2024-05-02 10:15:00",
+            "value": "149.00",
+            "comment": "",
+            "voucherdate": "2024-05-02",
+            "expiredate": "0000-00-00",
+            "gender": "w",
+            "firstname": "Erika",
+            "lastname": "Mustermann",
+            "street": "Hauptstra&szlig;e 1",
+            "zipcode": "12345",
+            "town": "Musterstadt",
+            "email": "erika@example.com",
+            "phonenumber": "",
+            "passenger": "Erika Mustermann",
+            "statustext": "Aktiviert"
+        }"#;
+
+        let voucher: Voucher = serde_json::from_str(json).unwrap();
+        assert_eq!(voucher.vid, "42");
+        assert_eq!(voucher.voucher_id, "GS-2024/007");
+        assert_eq!(voucher.title, "Rundflug \"Alpen\"");
+        assert_eq!(voucher.value, "149.00");
+        assert_eq!(voucher.gender, Gender::Female);
+        assert_eq!(voucher.street, "Hauptstraße 1");
+        assert_eq!(voucher.status_text, "Aktiviert");
+
+        #[cfg(feature = "chrono")]
+        {
+            let date = voucher.parsed_voucher_date().unwrap().unwrap();
+            assert_eq!(date.to_string(), "2024-05-02");
+            assert_eq!(voucher.parsed_expire_date().unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn voucher_status() {
+        let status = |json| serde_json::from_str::<VoucherStatus>(json).unwrap();
+        assert_eq!(status(r#""1""#), VoucherStatus::Created);
+        assert_eq!(status("3"), VoucherStatus::Redeemed);
+        assert_eq!(status(r#""6""#), VoucherStatus::PartiallyRedeemed);
+        assert_eq!(status(r#""7""#), VoucherStatus::Other("7".to_string()));
+    }
+
+    fn new_voucher(insert_new_user: bool) -> NewVoucher<'static> {
+        NewVoucher {
+            voucher_id: "GS-2024/007",
+            title: "Rundflug",
+            value: 149.0.into(),
+            insert_new_user,
+            comment: None,
+            voucher_date: Some("2024-05-02".into()),
+            expire_date: None,
+            gender: None,
+            first_name: None,
+            last_name: None,
+            street: None,
+            zipcode: None,
+            town: None,
+            email: None,
+            phone_number: None,
+            passenger: None,
+            status: Some(VoucherStatus::Activated),
+        }
+    }
+
+    #[test]
+    fn new_voucher_flag() {
+        let to_string = |voucher: &NewVoucher<'_>| {
+            serde_urlencoded::to_string(WithAccessToken::new("token", voucher)).unwrap()
+        };
+
+        assert_eq!(
+            to_string(&new_voucher(true)),
+            "accesstoken=token&voucherid=GS-2024%2F007&title=Rundflug&value=149.0\
+             &insertnewuser=1&voucherdate=2024-05-02&status=2"
+        );
+        assert_eq!(
+            to_string(&new_voucher(false)),
+            "accesstoken=token&voucherid=GS-2024%2F007&title=Rundflug&value=149.0\
+             &insertnewuser=0&voucherdate=2024-05-02&status=2"
+        );
+    }
+}
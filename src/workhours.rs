@@ -0,0 +1,303 @@
+use crate::error::error_for_status;
+use crate::utils::list::parse_list;
+use crate::{BaseUrl, Date, WithAccessToken};
+
+/// Get all work hours with a date within the given date range
+/// (`YYYY-mm-dd`).
+pub async fn list_work_hours(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    date_from: &str,
+    date_to: &str,
+) -> crate::Result<Vec<WorkHours>> {
+    #[derive(serde::Serialize)]
+    struct Params<'a> {
+        #[serde(rename = "datefrom")]
+        date_from: &'a str,
+        #[serde(rename = "dateto")]
+        date_to: &'a str,
+    }
+
+    let params = Params { date_from, date_to };
+    let params = WithAccessToken::new(access_token, &params);
+
+    let response = client
+        .post(base_url.endpoint("workhours/list/daterange"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    parse_list(&bytes)
+}
+
+pub async fn add_work_hours(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+    new_work_hours: &NewWorkHours<'_>,
+) -> crate::Result<WorkHours> {
+    let params = WithAccessToken::new(access_token, new_work_hours);
+
+    let response = client
+        .post(base_url.endpoint("workhours/add"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    let work_hours =
+        serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?;
+
+    Ok(work_hours)
+}
+
+pub async fn list_work_hour_categories(
+    client: &reqwest::Client,
+    base_url: &BaseUrl,
+    access_token: &str,
+) -> crate::Result<Vec<WorkHourCategory>> {
+    let params = WithAccessToken::new(access_token, &());
+
+    let response = client
+        .post(base_url.endpoint("workhourcategories/list"))
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(serde_urlencoded::to_string(params)?)
+        .send()
+        .await?;
+
+    let bytes = error_for_status(response).await?.bytes().await?;
+
+    parse_list(&bytes)
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct WorkHours {
+    /// Eindeutige ID
+    #[serde(rename = "whid")]
+    pub work_hours_id: String,
+    /// Erstellungsdatum
+    #[serde(rename = "createtime")]
+    pub create_time: String,
+    /// Letzte Änderung
+    #[serde(rename = "modifytime")]
+    pub modify_time: String,
+
+    /// ID der Person
+    #[serde(rename = "uid")]
+    pub user_id: String,
+    /// Name
+    #[serde(
+        rename = "username",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub user_name: String,
+
+    /// Arbeitsstundendatum
+    #[serde(rename = "jobdate")]
+    pub job_date: String,
+    /// Durchgeführte Tätigkeit
+    #[serde(rename = "jobtext", deserialize_with = "crate::utils::serde::unescape")]
+    pub job_text: String,
+    /// Dauer der Arbeit
+    pub hours: String,
+    /// Zeitraum von
+    #[serde(rename = "timefrom")]
+    pub time_from: String,
+    /// Zeitraum bis
+    #[serde(rename = "timeto")]
+    pub time_to: String,
+
+    /// Status
+    pub status: WorkHoursStatus,
+    /// Statusbezeichnung
+    #[serde(
+        rename = "statusinfo",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub status_info: String,
+
+    /// Kategorie
+    pub category: String,
+    /// Kategoriebezeichnung
+    #[serde(
+        rename = "categoryinfo",
+        deserialize_with = "crate::utils::serde::unescape"
+    )]
+    pub category_info: String,
+
+    /// Kommentar
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub comment: String,
+}
+
+#[cfg(feature = "chrono")]
+impl WorkHours {
+    /// Returns when the work hours were entered.
+    pub fn parsed_create_time(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.create_time)
+    }
+
+    /// Returns when the work hours were last changed, or `None` if they were
+    /// never changed.
+    pub fn parsed_modify_time(&self) -> crate::Result<Option<chrono::NaiveDateTime>> {
+        crate::utils::date::parse_datetime(&self.modify_time)
+    }
+
+    /// Returns the day the work was done.
+    pub fn parsed_job_date(&self) -> crate::Result<Option<chrono::NaiveDate>> {
+        crate::utils::date::parse_date(&self.job_date)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct NewWorkHours<'a> {
+    /// Id der Person
+    #[serde(rename = "uid")]
+    pub user_id: u32,
+
+    /// Datum (`YYYY-mm-dd`)
+    #[serde(rename = "jobdate")]
+    pub job_date: Date<'a>,
+
+    /// Tätigkeit
+    #[serde(rename = "jobtext")]
+    pub job_text: &'a str,
+
+    /// Stunden (`HH:ii`)
+    pub hours: &'a str,
+
+    /// Id der Arbeitsstundenkategorie
+    pub category: u32,
+
+    /// Uhrzeit ab (`HH:ii`)
+    #[serde(rename = "timefrom")]
+    pub time_from: Option<&'a str>,
+
+    /// Uhrzeit bis (`HH:ii`)
+    #[serde(rename = "timeto")]
+    pub time_to: Option<&'a str>,
+
+    /// Status
+    pub status: Option<WorkHoursStatus>,
+
+    /// Kommentar
+    pub comment: Option<&'a str>,
+}
+
+crate::utils::serde::code_enum! {
+    /// Status von Arbeitsstunden
+    pub enum WorkHoursStatus {
+        /// Angelegt (`1`)
+        Created = "1",
+        /// Akzeptiert (`2`)
+        Accepted = "2",
+        /// Abgelehnt (`3`)
+        Rejected = "3",
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct WorkHourCategory {
+    /// Eindeutige Id der Kategorie
+    #[serde(rename = "category")]
+    pub category_id: String,
+    /// Bezeichnung
+    #[serde(deserialize_with = "crate::utils::serde::unescape")]
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn work_hours(status: &str) -> WorkHours {
+        let json = format!(
+            r#"{{
+                "whid": "815",
+                "createtime": "2024-04-06 18:12:45",
+                "modifytime": "0000-00-00 00:00:00",
+                "uid": "11011",
+                "username": "M&uuml;ller, Max",
+                "jobdate": "2024-04-06",
+                "jobtext": "Winterarbeit &amp; Flugzeugpflege",
+                "hours": "03:30",
+                "timefrom": "09:00",
+                "timeto": "12:30",
+                "status": {status},
+                "statusinfo": "Angelegt",
+                "category": "2",
+                "categoryinfo": "Werkstatt",
+                "comment": ""
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn work_hours_list_entry() {
+        let work_hours = work_hours(r#""1""#);
+        assert_eq!(work_hours.work_hours_id, "815");
+        assert_eq!(work_hours.user_name, "Müller, Max");
+        assert_eq!(work_hours.job_text, "Winterarbeit & Flugzeugpflege");
+        assert_eq!(work_hours.hours, "03:30");
+        assert_eq!(work_hours.status, WorkHoursStatus::Created);
+        assert_eq!(work_hours.category_info, "Werkstatt");
+    }
+
+    #[test]
+    fn work_hours_status() {
+        assert_eq!(work_hours(r#""2""#).status, WorkHoursStatus::Accepted);
+        assert_eq!(work_hours("3").status, WorkHoursStatus::Rejected);
+        assert_eq!(
+            work_hours(r#""9""#).status,
+            WorkHoursStatus::Other("9".to_string())
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn work_hours_dates() {
+        let work_hours = work_hours(r#""1""#);
+        assert_eq!(
+            work_hours
+                .parsed_create_time()
+                .unwrap()
+                .unwrap()
+                .to_string(),
+            "2024-04-06 18:12:45"
+        );
+        assert_eq!(work_hours.parsed_modify_time().unwrap(), None);
+        assert_eq!(
+            work_hours.parsed_job_date().unwrap().unwrap().to_string(),
+            "2024-04-06"
+        );
+    }
+
+    #[test]
+    fn new_work_hours() {
+        let new_work_hours = NewWorkHours {
+            user_id: 11011,
+            job_date: "2024-04-06".into(),
+            job_text: "Winterarbeit & Flugzeugpflege",
+            hours: "03:30",
+            category: 2,
+            time_from: None,
+            time_to: None,
+            status: Some(WorkHoursStatus::Accepted),
+            comment: None,
+        };
+
+        let params = WithAccessToken::new("token", &new_work_hours);
+        assert_eq!(
+            serde_urlencoded::to_string(params).unwrap(),
+            "accesstoken=token&uid=11011&jobdate=2024-04-06\
+             &jobtext=Winterarbeit+%26+Flugzeugpflege&hours=03%3A30&category=2&status=2"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::AsyncWrite;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// A writer that records how many writes it has received.
+#[derive(Default)]
+struct RecordingWriter {
+    data: Vec<u8>,
+    writes: usize,
+}
+
+impl AsyncWrite for RecordingWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.writes += 1;
+        self.data.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn backups_are_streamed_in_chunks() {
+    let body = (0..4 * 1024 * 1024)
+        .map(|i| (i % 251) as u8)
+        .collect::<Vec<_>>();
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/interface/rest/backup/getzip"))
+        .and(query_param("accesstoken", "old"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut writer = RecordingWriter::default();
+    let written = common::client(&server)
+        .download_backup(&mut writer)
+        .await
+        .unwrap();
+
+    assert_eq!(written, body.len() as u64);
+    assert!(writer.data == body);
+    assert!(writer.writes > 1, "{} writes", writer.writes);
+}
+
+#[tokio::test]
+async fn failed_downloads_write_nothing() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/interface/rest/backup/getzip"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("error"))
+        .mount(&server)
+        .await;
+
+    let mut writer = RecordingWriter::default();
+    let result = common::client(&server).download_backup(&mut writer).await;
+
+    assert!(result.is_err());
+    assert!(writer.data.is_empty());
+}
@@ -0,0 +1,41 @@
+use vereinsflieger::BaseUrl;
+use wiremock::matchers::{body_string, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn public_appointments_use_the_homepage_access_code() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/calendar/list/public"))
+        .and(body_string("hpaccesscode=abc%26123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "0": {
+                "title": "Tag der offenen T&uuml;r",
+                "datefrom": "2024-06-01 00:00:00",
+                "dateto": "2024-06-02 00:00:00",
+                "wholeday": "1",
+                "location": "Flugplatz",
+                "comment": "",
+                "daterange": "01.06.2024 - 02.06.2024",
+                "duration": "2 Tage",
+            },
+            "httpstatuscode": 200,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let appointments = vereinsflieger::list_public_appointments(
+        &reqwest::Client::new(),
+        &BaseUrl::new(server.uri()),
+        "abc&123",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(appointments.len(), 1);
+    assert_eq!(appointments[0].title, "Tag der offenen Tür");
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests[0].url.query(), None);
+}
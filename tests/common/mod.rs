@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+use std::time::Duration;
+use vereinsflieger::{BaseUrl, Client, ClientBuilder, Credentials, RetryPolicy};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+pub fn credentials() -> Credentials {
+    Credentials {
+        club_id: None,
+        app_key: "app-key".to_string(),
+        username: "username".to_string(),
+        password: "password".to_string(),
+        auth_secret: None,
+    }
+}
+
+/// A builder for a client of the mock server, with the `old` access token,
+/// without rate limit and without retries.
+pub fn client_builder(server: &MockServer) -> ClientBuilder {
+    Client::builder(credentials())
+        .base_url(BaseUrl::new(server.uri()))
+        .access_token("old")
+        .rate_limit(None)
+        .retry_policy(RetryPolicy::never())
+}
+
+pub fn client(server: &MockServer) -> Client {
+    client_builder(server).build().unwrap()
+}
+
+/// Mock the sign-in endpoints, expecting exactly `count` sign-ins that
+/// result in the `new` access token.
+pub async fn mock_sign_in(server: &MockServer, count: u64) {
+    Mock::given(method("GET"))
+        .and(path("/interface/rest/auth/accesstoken"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "accesstoken": "new" })),
+        )
+        .expect(count)
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/auth/signin"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(200)))
+        .expect(count)
+        .mount(server)
+        .await;
+}
+
+pub fn unauthorized() -> ResponseTemplate {
+    ResponseTemplate::new(401).set_body_json(serde_json::json!({ "error": "Unauthorized" }))
+}
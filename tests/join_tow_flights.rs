@@ -0,0 +1,67 @@
+mod common;
+
+use vereinsflieger::Error;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mock_join(server: &MockServer, access_token: &str, response: ResponseTemplate) {
+    Mock::given(method("PUT"))
+        .and(path("/interface/rest/flight/jointowflights"))
+        .and(body_string_contains(format!("accesstoken={access_token}")))
+        .and(body_string_contains("flid=1&flidtow=2"))
+        .respond_with(response)
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+async fn mock_current_user(server: &MockServer, access_token: &str, response: ResponseTemplate) {
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/auth/getuser"))
+        .and(body_string_contains(format!("accesstoken={access_token}")))
+        .respond_with(response)
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+fn current_user() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "uid": "1",
+        "firstname": "Max",
+        "lastname": "Mustermann",
+        "memberid": "42",
+        "status": "Aktiv",
+        "roles": [],
+        "email": "max@example.com",
+    }))
+}
+
+#[tokio::test]
+async fn failed_join_with_valid_access_token() {
+    let server = MockServer::start().await;
+    common::mock_sign_in(&server, 0).await;
+    mock_join(&server, "old", common::unauthorized()).await;
+    mock_current_user(&server, "old", current_user()).await;
+
+    let error = common::client(&server)
+        .join_tow_flights(1, 2)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::JoinTowFlightsFailed), "{error:?}");
+}
+
+#[tokio::test]
+async fn join_with_invalid_access_token_is_sent_again() {
+    let server = MockServer::start().await;
+    common::mock_sign_in(&server, 1).await;
+    mock_join(&server, "old", common::unauthorized()).await;
+    mock_current_user(&server, "old", common::unauthorized()).await;
+    mock_current_user(&server, "new", current_user()).await;
+    mock_join(&server, "new", ResponseTemplate::new(200)).await;
+
+    common::client(&server)
+        .join_tow_flights(1, 2)
+        .await
+        .unwrap();
+}
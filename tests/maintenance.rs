@@ -0,0 +1,94 @@
+mod common;
+
+use vereinsflieger::Error;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mock_response(server: &MockServer, path_: &str, response: ResponseTemplate) {
+    Mock::given(method("POST"))
+        .and(path(path_))
+        .respond_with(response)
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn aircraft_counters() {
+    let server = MockServer::start().await;
+    let response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "motortime": "1234.5",
+        "flighttime": "2345.75",
+        "landingcount": "3456",
+        "httpstatuscode": 200,
+    }));
+    mock_response(
+        &server,
+        "/interface/rest/maintenance/airplane/D-KABC",
+        response,
+    )
+    .await;
+
+    let status = common::client(&server)
+        .aircraft_counters("D-KABC")
+        .await
+        .unwrap();
+    assert_eq!(status.motor_time, "1234.5");
+    assert_eq!(status.flight_time, "2345.75");
+    assert_eq!(status.landing_count, "3456");
+}
+
+#[tokio::test]
+async fn callsigns_are_percent_encoded() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/maintenance/airplane/D%20K%2FA%3FBC"))
+        .and(body_string_contains("callsign=D+K%2FA%3FBC"))
+        .respond_with(ResponseTemplate::new(400))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let error = common::client(&server)
+        .aircraft_counters("D K/A?BC")
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::UnknownAircraft(callsign) if callsign == "D K/A?BC"));
+}
+
+#[tokio::test]
+async fn bad_requests_are_unknown_aircraft() {
+    let server = MockServer::start().await;
+    let response = ResponseTemplate::new(400)
+        .set_body_json(serde_json::json!({ "error": "Ungültige Anfrage" }));
+    mock_response(
+        &server,
+        "/interface/rest/maintenance/airplane/D-KABC",
+        response,
+    )
+    .await;
+
+    let error = common::client(&server)
+        .aircraft_counters("D-KABC")
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::UnknownAircraft(callsign) if callsign == "D-KABC"));
+}
+
+#[tokio::test]
+async fn other_errors_are_not_unknown_aircraft() {
+    let server = MockServer::start().await;
+    let response = ResponseTemplate::new(404);
+    mock_response(
+        &server,
+        "/interface/rest/maintenance/airplane/D-KABC",
+        response,
+    )
+    .await;
+
+    let error = common::client(&server)
+        .aircraft_counters("D-KABC")
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::RequestFailed(_)), "{error:?}");
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use std::time::{Duration, Instant};
+use vereinsflieger::{Error, NewSale, RetryPolicy};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy::default().initial_backoff(Duration::from_millis(10))
+}
+
+async fn mock_user_list(server: &MockServer, failures: u64) {
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/user/list"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(failures)
+        .expect(failures)
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/user/list"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "httpstatuscode": 200 })),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn server_errors_are_retried() {
+    let server = MockServer::start().await;
+    mock_user_list(&server, 2).await;
+
+    let client = common::client_builder(&server)
+        .retry_policy(retry_policy())
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    assert!(client.list_users().await.unwrap().is_empty());
+
+    // The backoffs are at least 5ms and 10ms, because of the jitter.
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(15), "{elapsed:?}");
+}
+
+#[tokio::test]
+async fn max_attempts_is_honoured() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/user/list"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(4)
+        .mount(&server)
+        .await;
+
+    let client = common::client_builder(&server)
+        .retry_policy(retry_policy().max_attempts(4))
+        .build()
+        .unwrap();
+
+    let error = client.list_users().await.unwrap_err();
+    assert!(matches!(error, Error::RequestFailed(_)), "{error:?}");
+}
+
+async fn mock_failing_sale(server: &MockServer, count: u64) {
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/sale/add"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(count)
+        .mount(server)
+        .await;
+}
+
+fn sale() -> NewSale<'static> {
+    NewSale {
+        booking_date: "2024-03-05".into(),
+        article_id: "1",
+        amount: 1.0.into(),
+        member_id: None,
+        callsign: None,
+        sales_tax: None,
+        total_price: None,
+        counter: None,
+        comment: None,
+        cost_type: None,
+        caid2: None,
+        spid: None,
+    }
+}
+
+#[tokio::test]
+async fn non_idempotent_requests_are_not_retried_by_default() {
+    let server = MockServer::start().await;
+    mock_failing_sale(&server, 1).await;
+
+    let client = common::client_builder(&server)
+        .retry_policy(retry_policy())
+        .build()
+        .unwrap();
+
+    client.add_sale(&sale()).await.unwrap_err();
+}
+
+#[tokio::test]
+async fn non_idempotent_requests_can_be_retried() {
+    let server = MockServer::start().await;
+    mock_failing_sale(&server, 3).await;
+
+    let client = common::client_builder(&server)
+        .retry_policy(retry_policy().retry_non_idempotent(true))
+        .build()
+        .unwrap();
+
+    client.add_sale(&sale()).await.unwrap_err();
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+use std::time::Duration;
+use vereinsflieger::{
+    BaseUrl, Client, Credentials, MemoryTokenStore, RetryPolicy, TokenKey, TokenStore,
+};
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn credentials() -> Credentials {
+    Credentials {
+        club_id: None,
+        app_key: "app-key".to_string(),
+        username: "username".to_string(),
+        password: "password".to_string(),
+        auth_secret: None,
+    }
+}
+
+fn client(server: &MockServer, token_store: Arc<MemoryTokenStore>) -> Client {
+    Client::builder(credentials())
+        .base_url(BaseUrl::new(server.uri()))
+        .access_token("old")
+        .rate_limit(None)
+        .retry_policy(RetryPolicy::never())
+        .token_store(token_store)
+        .build()
+        .unwrap()
+}
+
+/// Mock the sign-in endpoints, expecting exactly `count` sign-ins.
+async fn mock_sign_in(server: &MockServer, count: u64) {
+    Mock::given(method("GET"))
+        .and(path("/interface/rest/auth/accesstoken"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "accesstoken": "new" })),
+        )
+        .expect(count)
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/auth/signin"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(200)))
+        .expect(count)
+        .mount(server)
+        .await;
+}
+
+/// Mock the `user/list` endpoint, which only accepts the `new` access token.
+async fn mock_user_list(server: &MockServer, invalid_access_tokens: &[&str]) {
+    for access_token in invalid_access_tokens {
+        Mock::given(method("POST"))
+            .and(path("/interface/rest/user/list"))
+            .and(body_string_contains(format!("accesstoken={access_token}")))
+            .respond_with(
+                ResponseTemplate::new(401)
+                    .set_body_json(serde_json::json!({ "error": "Unauthorized" })),
+            )
+            .mount(server)
+            .await;
+    }
+
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/user/list"))
+        .and(body_string_contains("accesstoken=new"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "httpstatuscode": 200 })),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn concurrent_unauthorized_requests_refresh_once() {
+    let server = MockServer::start().await;
+    mock_sign_in(&server, 1).await;
+    mock_user_list(&server, &["old"]).await;
+
+    let client = client(&server, Default::default());
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for _ in 0..5 {
+        let client = client.clone();
+        tasks.spawn(async move { client.list_users().await });
+    }
+
+    for result in tasks.join_all().await {
+        assert!(result.unwrap().is_empty());
+    }
+}
+
+#[tokio::test]
+async fn clients_sharing_a_token_store_reuse_refreshed_tokens() {
+    let server = MockServer::start().await;
+    mock_sign_in(&server, 1).await;
+    mock_user_list(&server, &["old"]).await;
+
+    let token_store = Arc::new(MemoryTokenStore::new());
+    let first = client(&server, token_store.clone());
+    let second = client(&server, token_store);
+
+    first.list_users().await.unwrap();
+    second.list_users().await.unwrap();
+}
+
+#[tokio::test]
+async fn invalid_tokens_from_the_token_store_trigger_a_new_sign_in() {
+    let server = MockServer::start().await;
+    mock_sign_in(&server, 1).await;
+    mock_user_list(&server, &["old", "stale"]).await;
+
+    let token_store = Arc::new(MemoryTokenStore::new());
+    let client = client(&server, token_store.clone());
+
+    // Another client has saved an access token that is invalid as well.
+    let base_url = BaseUrl::new(server.uri());
+    let credentials = credentials();
+    let key = TokenKey::new(&base_url, &credentials);
+    token_store.save(&key, Some("stale")).unwrap();
+
+    client.list_users().await.unwrap();
+    assert_eq!(token_store.load(&key).unwrap().as_deref(), Some("new"));
+}
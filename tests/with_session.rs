@@ -0,0 +1,70 @@
+mod common;
+
+use std::sync::Arc;
+use vereinsflieger::{BaseUrl, MemoryTokenStore, TokenKey, TokenStore};
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mock_user_list(server: &MockServer, access_token: &str, count: u64) {
+    Mock::given(method("POST"))
+        .and(path("/interface/rest/user/list"))
+        .and(body_string_contains(format!("accesstoken={access_token}")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "httpstatuscode": 200 })),
+        )
+        .expect(count)
+        .mount(server)
+        .await;
+}
+
+async fn mock_sign_out(server: &MockServer, status: u16) {
+    Mock::given(method("DELETE"))
+        .and(path("/interface/rest/auth/signout/new"))
+        .respond_with(ResponseTemplate::new(status))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn sessions_do_not_affect_the_parent_client() {
+    let server = MockServer::start().await;
+    common::mock_sign_in(&server, 1).await;
+    mock_user_list(&server, "new", 1).await;
+    mock_user_list(&server, "old", 1).await;
+    mock_sign_out(&server, 200).await;
+
+    let token_store = Arc::new(MemoryTokenStore::new());
+    let client = common::client_builder(&server)
+        .token_store(token_store.clone())
+        .build()
+        .unwrap();
+
+    let users = client
+        .with_session(|session| async move { session.list_users().await })
+        .await
+        .unwrap();
+    assert!(users.is_empty());
+
+    // The parent client still uses its own access token.
+    client.list_users().await.unwrap();
+
+    let base_url = BaseUrl::new(server.uri());
+    let credentials = common::credentials();
+    let key = TokenKey::new(&base_url, &credentials);
+    assert_eq!(token_store.load(&key).unwrap().as_deref(), Some("old"));
+}
+
+#[tokio::test]
+async fn failed_sign_out_keeps_the_result() {
+    let server = MockServer::start().await;
+    common::mock_sign_in(&server, 1).await;
+    mock_user_list(&server, "new", 1).await;
+    mock_sign_out(&server, 500).await;
+
+    let client = common::client(&server);
+    let result = client
+        .with_session(|session| async move { session.list_users().await })
+        .await;
+    assert!(result.unwrap().is_empty());
+}